@@ -0,0 +1,42 @@
+//! Compares `argsort` and `argsort_unstable` on a large array with many ties.
+//!
+//! Run with `cargo bench --bench argsort`.
+extern crate ndarray;
+extern crate ndarray_stats;
+
+use ndarray::Array1;
+use ndarray_stats::Sort1dExt;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 1_000_000;
+const N_DISTINCT: u64 = 100;
+const N_RUNS: usize = 10;
+
+/// Returns the fastest of `N_RUNS` timings of `f`.
+fn fastest<F, T>(mut f: F) -> Duration
+where
+    F: FnMut() -> T,
+{
+    (0..N_RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            drop(result);
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    // Multiplicative hashing spreads `N_DISTINCT` levels over the array.
+    let a: Array1<u64> = (0..LEN as u64)
+        .map(|i| i.wrapping_mul(2_654_435_761) % N_DISTINCT)
+        .collect();
+    let stable = fastest(|| a.argsort());
+    let unstable = fastest(|| a.argsort_unstable());
+    println!("{} elements, {} distinct values", LEN, N_DISTINCT);
+    println!("argsort:          {:?}", stable);
+    println!("argsort_unstable: {:?}", unstable);
+}
@@ -0,0 +1,40 @@
+//! Compares the linear min/max scan used by `quantile_mut` for `q=0` and `q=1`
+//! with the general selection used for the other quantiles.
+//!
+//! Run with `cargo bench --bench quantile`.
+extern crate ndarray;
+extern crate ndarray_stats;
+
+use ndarray::Array1;
+use ndarray_stats::{interpolate::Linear, Quantile1dExt};
+use std::time::{Duration, Instant};
+
+const LEN: usize = 1_000_000;
+const N_RUNS: usize = 10;
+
+/// Returns the fastest of `N_RUNS` timings of `quantile_mut::<Linear>(q)`,
+/// each on a fresh copy of `a`.
+fn fastest(a: &Array1<i64>, q: f64) -> Duration {
+    (0..N_RUNS)
+        .map(|_| {
+            let mut a = a.clone();
+            let start = Instant::now();
+            let quantile = a.quantile_mut::<Linear>(q);
+            let elapsed = start.elapsed();
+            assert!(quantile.is_some());
+            elapsed
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    // Multiplicative hashing scrambles `0..LEN`.
+    let a: Array1<i64> = (0..LEN as i64)
+        .map(|i| i.wrapping_mul(2_654_435_761) % LEN as i64)
+        .collect();
+    println!("{} elements", LEN);
+    for &q in &[0., 1e-6, 0.5, 1. - 1e-6, 1.] {
+        println!("q = {:<9}: {:?}", q, fastest(&a, q));
+    }
+}
@@ -1,6 +1,9 @@
+use errors::MultiInputError;
 use ndarray::prelude::*;
 use ndarray::Data;
 use num_traits::{Float, FromPrimitive};
+use rank::{rank_lane_by, TieMethod};
+use std::cmp::Ordering;
 
 /// Extension trait for `ArrayBase` providing functions
 /// to compute different correlation measures.
@@ -66,6 +69,136 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Return the covariance matrix `C` for a 2-dimensional array of
+    /// observations `M` where **columns are random variables** and rows
+    /// are observations (i.e. NumPy's `np.cov` with `rowvar=False`).
+    ///
+    /// Let `(n, m)` be the shape of `M`: `C` has shape `(m, m)` and it is
+    /// equal to the covariance matrix returned by [`cov`](#tymethod.cov)
+    /// for the transpose of `M`. See [`cov`](#tymethod.cov) for the definition
+    /// of covariance and for the meaning of `ddof`.
+    ///
+    /// **Panics** if `ddof` is greater than or equal to the number of
+    /// observations, if the number of observations is zero and division by
+    /// zero panics for type `A`, or if the type cast of `n_observations` from
+    /// `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{aview2, arr2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.]]);
+    /// let covariance = a.cov_columns(1.);
+    /// assert_eq!(
+    ///    covariance,
+    ///    aview2(&[[4., 4.], [4., 4.]])
+    /// );
+    /// ```
+    fn cov_columns(&self, ddof: A) -> Array2<A>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the covariance matrix `C` for a 2-dimensional array of
+    /// observations `M` that has **already been centered**, i.e. the mean
+    /// of each row of `M` is zero.
+    ///
+    /// **Warning**: the precondition is not checked! If the rows of `M`
+    /// do not have zero mean the result is **not** the covariance matrix.
+    ///
+    /// This skips the centering pass performed by [`cov`](#tymethod.cov),
+    /// which can be avoided when the centered data is needed anyway
+    /// (e.g. for standardization) or when computing the covariance
+    /// repeatedly in a tight loop. See [`cov`](#tymethod.cov) for details
+    /// on the shape of `M` and on `ddof`.
+    ///
+    /// **Panics** if `ddof` is greater than or equal to the number of
+    /// observations, if the number of observations is zero and division by
+    /// zero panics for type `A`, or if the type cast of `n_observations` from
+    /// `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{aview2, arr2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let centered = arr2(&[[-2., 0., 2.],
+    ///                       [-2., 0., 2.]]);
+    /// let covariance = centered.cov_precentered(1.);
+    /// assert_eq!(
+    ///    covariance,
+    ///    aview2(&[[4., 4.], [4., 4.]])
+    /// );
+    /// ```
+    fn cov_precentered(&self, ddof: A) -> Array2<A>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the weighted covariance matrix `C` for a 2-dimensional
+    /// array of observations `M`, where the `j`-th observation (i.e. the `j`-th
+    /// column of `M`) has weight `weights[j]`.
+    ///
+    /// See [`cov`](#tymethod.cov) for details on the shape of `M`.
+    ///
+    /// The observations are centered using the weighted mean and
+    /// the weighted covariance of two random variables is defined as:
+    ///
+    /// ```text
+    ///                      1          n
+    /// cov(X, Y) = ――――――――――――――――――  ∑ wᵢ(xᵢ - x̅)(yᵢ - y̅)
+    ///             V₁ - ddof (V₂ / V₁) i=1
+    /// ```
+    ///
+    /// where
+    ///
+    /// ```text
+    ///      n             n              ∑ᵢ wᵢxᵢ
+    /// V₁ = ∑ wᵢ,    V₂ = ∑ wᵢ²,    x̅ = ―――――――
+    ///     i=1           i=1               V₁
+    /// ```
+    ///
+    /// The normalization treats `weights` as reliability weights: with
+    /// `ddof = 1` the estimate is unbiased, and if all weights are equal
+    /// the result is the same as [`cov`](#tymethod.cov).
+    /// Weights are expected to be non-negative.
+    ///
+    /// The following **errors** may be returned:
+    /// - `MultiInputError::EmptyInput` if `M` has no observations;
+    /// - `MultiInputError::ShapeMismatch` if the length of `weights` is not
+    ///   equal to the number of observations.
+    ///
+    /// **Panics** if `V₁ - ddof (V₂ / V₁)` is not strictly positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{aview2, arr1, arr2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 3., 5.],
+    ///                [2., 4., 6.]]);
+    /// // weight 2 on the first observation: the weighted mean is (2.5, 3.5)
+    /// let covariance = a.weighted_cov(&arr1(&[2., 1., 1.]), 0.).unwrap();
+    /// assert_eq!(
+    ///    covariance,
+    ///    aview2(&[[2.75, 2.75], [2.75, 2.75]])
+    /// );
+    /// ```
+    fn weighted_cov<S2>(&self, weights: &ArrayBase<S2, Ix1>, ddof: A) -> Result<Array2<A>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
     /// Return the [Pearson correlation coefficients](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
     /// for a 2-dimensional array of observations `M`.
     ///
@@ -126,6 +259,23 @@ where
     S: Data<Elem = A>,
 {
     fn cov(&self, ddof: A) -> Array2<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        let observation_axis = Axis(1);
+        let mean = self.mean_axis(observation_axis);
+        let denoised = self - &mean.insert_axis(observation_axis);
+        denoised.cov_precentered(ddof)
+    }
+
+    fn cov_columns(&self, ddof: A) -> Array2<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        self.t().cov(ddof)
+    }
+
+    fn cov_precentered(&self, ddof: A) -> Array2<A>
     where
         A: Float + FromPrimitive,
     {
@@ -139,12 +289,38 @@ where
             } else {
                 n_observations - ddof
             };
-        let mean = self.mean_axis(observation_axis);
-        let denoised = self - &mean.insert_axis(observation_axis);
-        let covariance = denoised.dot(&denoised.t());
+        let covariance = self.dot(&self.t());
         covariance.mapv_into(|x| x / dof)
     }
 
+    fn weighted_cov<S2>(&self, weights: &ArrayBase<S2, Ix1>, ddof: A) -> Result<Array2<A>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let observation_axis = Axis(1);
+        let n_observations = self.len_of(observation_axis);
+        if n_observations == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if weights.len() != n_observations {
+            return Err(MultiInputError::ShapeMismatch {
+                first: self.shape().to_vec(),
+                second: weights.shape().to_vec(),
+            });
+        }
+        let v1 = weights.sum();
+        let v2 = weights.fold(A::zero(), |acc, &w| acc + w * w);
+        let denominator = v1 - ddof * v2 / v1;
+        assert!(denominator > A::zero(),
+                "`ddof` is too large for the provided weights: the normalization \
+                factor has to be strictly positive.");
+        let mean = (self * weights).sum_axis(observation_axis).mapv_into(|x| x / v1);
+        let centered = self - &mean.insert_axis(observation_axis);
+        let covariance = (&centered * weights).dot(&centered.t());
+        Ok(covariance.mapv_into(|x| x / denominator))
+    }
+
     fn pearson_correlation(&self) -> Array2<A>
     where
         A: Float + FromPrimitive,
@@ -163,6 +339,234 @@ where
     }
 }
 
+/// Extension trait for 1-dimensional `ArrayBase`s providing functions
+/// to compute correlation measures between pairs of random variables.
+pub trait Correlation1dExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Return [Kendall's tau-b](https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient#Tau-b)
+    /// rank correlation coefficient between the observations in `self` and the
+    /// paired observations in `other`.
+    ///
+    /// A pair of indices `(i, j)` is concordant if `xᵢ - xⱼ` and `yᵢ - yⱼ` have the
+    /// same sign, discordant if they have opposite signs.
+    /// Tau-b corrects for ties:
+    ///
+    /// ```text
+    ///                  n_c - n_d
+    /// τ_b = ――――――――――――――――――――――――――
+    ///       sqrt((n₀ - n₁)(n₀ - n₂))
+    /// ```
+    ///
+    /// where `n_c` and `n_d` are the number of concordant and discordant pairs,
+    /// `n₀ = n(n-1)/2` is the total number of pairs and `n₁` (respectively `n₂`)
+    /// is the number of pairs tied in `self` (respectively in `other`).
+    ///
+    /// NaN is returned if there are less than two observations, if all observations
+    /// are tied in either `self` or `other`, or if any element is NaN.
+    ///
+    /// Complexity: O(`n`^2), where `n` is the number of observations.
+    ///
+    /// Returns `Err(MultiInputError::ShapeMismatch)` if `self` and `other`
+    /// have different lengths.
+    ///
+    /// **Panics** if the type cast of the number of pairs from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Correlation1dExt;
+    ///
+    /// let x = array![1., 2., 3., 4.];
+    /// let y = array![10., 20., 30., 40.];
+    /// assert_eq!(x.kendall_tau(&y), Ok(1.));
+    /// ```
+    fn kendall_tau<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Return [Spearman's rank correlation coefficient](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient)
+    /// between the observations in `self` and the paired observations in `other`.
+    ///
+    /// It is the Pearson correlation coefficient of the ranks of the observations:
+    /// each array is ranked independently, giving tied elements the average
+    /// of the ranks they span (see [`TieMethod::Average`]).
+    ///
+    /// NaN is returned if there is a single observation, if all observations
+    /// are tied in either `self` or `other`, or if any element is NaN.
+    ///
+    /// Complexity: O(`n` log `n`), where `n` is the number of observations.
+    ///
+    /// The following **errors** may be returned:
+    /// - `MultiInputError::EmptyInput` if `self` is empty;
+    /// - `MultiInputError::ShapeMismatch` if `self` and `other` have
+    ///   different lengths.
+    ///
+    /// **Panics** if the type cast of the coefficient from `f64` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Correlation1dExt;
+    ///
+    /// // `y` is a monotonic (non-linear) function of `x`
+    /// let x = array![1., 2., 3., 4.];
+    /// let y = array![1., 8., 27., 64.];
+    /// assert_eq!(x.spearman(&y), Ok(1.));
+    /// ```
+    ///
+    /// [`TieMethod::Average`]: enum.TieMethod.html#variant.Average
+    fn spearman<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Return the statistic of the t-test for Spearman's rank correlation
+    /// coefficient `ρ` (see [`spearman`](#tymethod.spearman)) between
+    /// `self` and `other`:
+    ///
+    /// ```text
+    ///          ⎛  n - 2 ⎞½
+    /// t = ρ ⋅ ⎜ ―――――― ⎟
+    ///          ⎝ 1 - ρ² ⎠
+    /// ```
+    ///
+    /// where `n` is the number of observations.
+    ///
+    /// Under the null hypothesis of no correlation, `t` approximately follows
+    /// Student's t-distribution with `n - 2` degrees of freedom: the two-sided
+    /// p-value is `2 ⋅ (1 - F(|t|))`, where `F` is the cumulative distribution
+    /// function of that distribution (not provided by this crate).
+    /// The approximation is reasonable for `n` greater than about 10.
+    ///
+    /// `t` is infinite if `ρ = ±1` and NaN if `n < 3` or `ρ` is NaN.
+    ///
+    /// The same **errors** of [`spearman`](#tymethod.spearman) may be returned.
+    ///
+    /// **Panics** if the type cast of `n - 2` from `usize` to `A` fails.
+    fn spearman_t_statistic<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+}
+
+impl<A, S> Correlation1dExt<A, S> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    fn kendall_tau<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        if self.len() != other.len() {
+            return Err(MultiInputError::ShapeMismatch {
+                first: self.shape().to_vec(),
+                second: other.shape().to_vec(),
+            });
+        }
+        if self.iter().chain(other.iter()).any(|x| x.is_nan()) {
+            return Ok(A::nan());
+        }
+        // A naive O(n^2) scan over all pairs.
+        // TODO: switch to Knight's O(n log n) algorithm, based on merge sort,
+        // which counts discordant pairs as the number of swaps required to sort `other`
+        // after sorting the pairs with respect to `self`.
+        let n = self.len();
+        let mut concordant = 0;
+        let mut discordant = 0;
+        let mut tied_self = 0;
+        let mut tied_other = 0;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                // Comparing the elements, rather than the sign of their difference,
+                // treats equal infinite values as ties (`inf - inf` is NaN).
+                let x_order = self[i].partial_cmp(&self[j]).unwrap();
+                let y_order = other[i].partial_cmp(&other[j]).unwrap();
+                if x_order == Ordering::Equal || y_order == Ordering::Equal {
+                    if x_order == Ordering::Equal {
+                        tied_self += 1;
+                    }
+                    if y_order == Ordering::Equal {
+                        tied_other += 1;
+                    }
+                } else if x_order == y_order {
+                    concordant += 1;
+                } else {
+                    discordant += 1;
+                }
+            }
+        }
+        let n_pairs = n * n.saturating_sub(1) / 2;
+        let to_a = |count: usize| A::from_usize(count).unwrap();
+        let numerator = to_a(concordant) - to_a(discordant);
+        let denominator =
+            ((to_a(n_pairs) - to_a(tied_self)) * (to_a(n_pairs) - to_a(tied_other))).sqrt();
+        Ok(numerator / denominator)
+    }
+
+    fn spearman<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.len() != other.len() {
+            return Err(MultiInputError::ShapeMismatch {
+                first: self.shape().to_vec(),
+                second: other.shape().to_vec(),
+            });
+        }
+        if self.iter().chain(other.iter()).any(|x| x.is_nan()) {
+            return Ok(A::nan());
+        }
+        let rank = |x: ArrayView1<A>| {
+            let mut ranks = Array1::zeros(x.len());
+            // No NaN is left, hence all elements are comparable
+            rank_lane_by(&x, ranks.view_mut(), TieMethod::Average, |a, b| {
+                a.partial_cmp(b).unwrap()
+            });
+            ranks
+        };
+        let ranks = rank(self.view());
+        let other_ranks = rank(other.view());
+        // Both arrays of ranks have the same mean, `(n + 1) / 2`
+        let n = self.len() as f64;
+        let mean = (n + 1.) / 2.;
+        let (mut covariance, mut variance, mut other_variance) = (0., 0., 0.);
+        for (&r, &s) in ranks.iter().zip(&other_ranks) {
+            covariance += (r - mean) * (s - mean);
+            variance += (r - mean) * (r - mean);
+            other_variance += (s - mean) * (s - mean);
+        }
+        Ok(A::from_f64(covariance / (variance * other_variance).sqrt()).unwrap())
+    }
+
+    fn spearman_t_statistic<S2>(&self, other: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let rho = self.spearman(other)?;
+        let n = self.len();
+        if n < 3 {
+            return Ok(A::nan());
+        }
+        let dof = A::from_usize(n - 2).unwrap();
+        Ok(rho * (dof / (A::one() - rho * rho)).sqrt())
+    }
+}
+
 #[cfg(test)]
 mod cov_tests {
     use super::*;
@@ -193,6 +597,30 @@ mod cov_tests {
             let covariance = a.cov(1.);
             covariance.all_close(&covariance.t(), 1e-8)
         }
+
+        fn precentered_covariance_matches_covariance(bound: f64) -> bool {
+            let n_random_variables = 3;
+            let n_observations = 5;
+            let bound = bound.abs().min(1e3) + 1.;
+            let a = Array::random(
+                (n_random_variables, n_observations),
+                Uniform::new(-bound, bound)
+            );
+            let mean = a.mean_axis(Axis(1));
+            let centered = &a - &mean.insert_axis(Axis(1));
+            centered.cov_precentered(1.).all_close(&a.cov(1.), 1e-8 * bound * bound)
+        }
+    }
+
+    #[test]
+    fn test_cov_columns_matches_cov_of_transpose() {
+        let a = Array::random((6, 3), Uniform::new(-5., 5.));
+        let covariance = a.cov_columns(1.);
+        assert_eq!(covariance.dim(), (3, 3));
+        assert!(covariance.all_close(&a.t().cov(1.), 1e-12));
+        assert!(covariance.all_close(&a.clone().reversed_axes().cov(1.), 1e-12));
+        // Rows as random variables give a (6, 6) matrix instead
+        assert_eq!(a.cov(1.).dim(), (6, 6));
     }
 
     #[test]
@@ -277,6 +705,72 @@ mod cov_tests {
     }
 }
 
+#[cfg(test)]
+mod weighted_cov_tests {
+    use super::*;
+    use ndarray::array;
+    use rand::distributions::Uniform;
+    use ndarray_rand::RandomExt;
+
+    #[test]
+    fn test_uniform_weights_reproduce_cov() {
+        let a = Array::random((4, 7), Uniform::new(-5., 5.));
+        for &w in &[1., 0.25, 3.] {
+            let weights = Array1::from_elem(7, w);
+            for &ddof in &[0., 1.] {
+                let weighted = a.weighted_cov(&weights, ddof).unwrap();
+                assert!(weighted.all_close(&a.cov(ddof), 1e-10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_integer_weights_repeat_observations() {
+        // With ddof = 0, an observation with weight 2 counts as two observations
+        let a = array![[1., 4., 2.], [0., 3., 9.]];
+        let weights = array![2., 1., 3.];
+        let repeated = array![
+            [1., 1., 4., 2., 2., 2.],
+            [0., 0., 3., 9., 9., 9.],
+        ];
+        let weighted = a.weighted_cov(&weights, 0.).unwrap();
+        assert!(weighted.all_close(&repeated.cov(0.), 1e-12));
+    }
+
+    #[test]
+    fn test_zero_weights_drop_observations() {
+        let a = array![[1., 100., 3., 5.], [2., -100., 4., 6.]];
+        let weights = array![1., 0., 1., 1.];
+        let dropped = array![[1., 3., 5.], [2., 4., 6.]];
+        let weighted = a.weighted_cov(&weights, 0.).unwrap();
+        assert!(weighted.all_close(&dropped.cov(0.), 1e-12));
+    }
+
+    #[test]
+    fn test_weighted_cov_errors() {
+        let a = array![[1., 2.], [3., 4.]];
+        assert_eq!(
+            a.weighted_cov(&array![1., 2., 3.], 1.),
+            Err(MultiInputError::ShapeMismatch {
+                first: vec![2, 2],
+                second: vec![3],
+            })
+        );
+        let empty = Array2::<f64>::zeros((2, 0));
+        assert_eq!(
+            empty.weighted_cov(&Array1::zeros(0), 1.),
+            Err(MultiInputError::EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weighted_cov_with_invalid_ddof() {
+        let a = array![[1., 2.], [3., 4.]];
+        let _ = a.weighted_cov(&array![1., 1.], 2.);
+    }
+}
+
 #[cfg(test)]
 mod pearson_correlation_tests {
     use super::*;
@@ -353,3 +847,128 @@ mod pearson_correlation_tests {
     }
 
 }
+
+#[cfg(test)]
+mod kendall_tau_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_monotonic_pair() {
+        let x = array![1., 2., 3., 4., 5.];
+        let y = array![-3., 0., 2., 10., 11.];
+        assert_eq!(x.kendall_tau(&y), Ok(1.));
+    }
+
+    #[test]
+    fn test_reversed_pair() {
+        let x = array![1., 2., 3., 4., 5.];
+        let y = array![5., 4., 3., 2., 1.];
+        assert_eq!(x.kendall_tau(&y), Ok(-1.));
+    }
+
+    #[test]
+    fn test_with_ties() {
+        // 3 concordant pairs, 1 discordant pair, a pair tied in `x`
+        // and a pair tied in `y`: (3 - 1) / sqrt((6 - 1) * (6 - 1)).
+        let x = array![1., 2., 2., 3.];
+        let y = array![1., 3., 2., 2.];
+        assert!((x.kendall_tau(&y).unwrap() - 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_with_repeated_infinite_values() {
+        use std::f64;
+
+        // The two infinite values are a tie in `x`, as two equal finite values would be.
+        let x = array![f64::INFINITY, f64::INFINITY, 1., 2.];
+        let y = array![1., 2., 3., 4.];
+        let expected = array![10., 10., 1., 2.].kendall_tau(&y).unwrap();
+        assert_eq!(x.kendall_tau(&y), Ok(expected));
+        assert!((expected + 3. / 30f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_constant_variable() {
+        let x = array![1., 1., 1.];
+        let y = array![1., 2., 3.];
+        assert!(x.kendall_tau(&y).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let x = array![1., 2., 3.];
+        let y = array![1., 2.];
+        assert_eq!(
+            x.kendall_tau(&y),
+            Err(MultiInputError::ShapeMismatch {
+                first: vec![3],
+                second: vec![2],
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod spearman_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_monotonic_pair() {
+        let x = array![0.5, 1., 2., 4., 8.];
+        let y = x.mapv(|v: f64| v.exp());
+        assert_eq!(x.spearman(&y), Ok(1.));
+        assert_eq!(x.spearman(&-y), Ok(-1.));
+    }
+
+    #[test]
+    fn test_with_ties() {
+        // The ranks of `y` are [2, 1, 3, 5.5, 5.5, 4]: their covariance with
+        // the ranks of `x` is 13 / 6, while the variances are 17.5 / 6 and 17 / 6
+        let x = array![1., 2., 3., 4., 5., 6.];
+        let y = array![2., 1., 4., 8., 8., 5.];
+        let expected = 13. / (17.5f64 * 17.).sqrt();
+        assert!((x.spearman(&y).unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_matches_pearson_correlation_of_ranks() {
+        let x = array![3., -1., 2.5, 7., 0.];
+        let y = array![0.1, 0.4, -0.3, 0.2, 0.];
+        let ranks = array![[4., 1., 3., 5., 2.], [3., 5., 1., 4., 2.]];
+        let pearson = ranks.pearson_correlation()[[0, 1]];
+        assert!((x.spearman(&y).unwrap() - pearson).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        assert!(array![1.].spearman(&array![2.]).unwrap().is_nan());
+        assert!(array![1., 1., 1.].spearman(&array![1., 2., 3.]).unwrap().is_nan());
+        assert!(array![1., ::std::f64::NAN].spearman(&array![1., 2.]).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_errors() {
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.spearman(&empty), Err(MultiInputError::EmptyInput));
+        assert_eq!(
+            array![1., 2., 3.].spearman(&array![1., 2.]),
+            Err(MultiInputError::ShapeMismatch {
+                first: vec![3],
+                second: vec![2],
+            })
+        );
+    }
+
+    #[test]
+    fn test_t_statistic() {
+        let x = array![1., 2., 3., 4., 5., 6.];
+        let y = array![2., 1., 4., 8., 8., 5.];
+        let rho = x.spearman(&y).unwrap();
+        let t = x.spearman_t_statistic(&y).unwrap();
+        assert!((t - rho * (4. / (1. - rho * rho)).sqrt()).abs() < 1e-12);
+        assert_eq!(x.spearman_t_statistic(&x), Ok(::std::f64::INFINITY));
+        assert!(array![1., 2.].spearman_t_statistic(&array![2., 1.]).unwrap().is_nan());
+    }
+}
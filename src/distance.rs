@@ -0,0 +1,212 @@
+//! Distance functionalities.
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::Float;
+
+/// A distance between two 1-dimensional arrays of the same length.
+///
+/// It is used by [`pairwise_distances`] to compare observations:
+/// implement it to provide a custom metric (e.g. Mahalanobis, Haversine).
+///
+/// [`pairwise_distances`]: ../trait.DistanceExt.html#tymethod.pairwise_distances
+///
+/// # Example:
+///
+/// ```
+/// extern crate ndarray_stats;
+/// extern crate ndarray;
+/// use ndarray::{array, ArrayView1};
+/// use ndarray_stats::distance::Distance;
+///
+/// /// Number of positions where the two arrays differ.
+/// struct Hamming;
+///
+/// impl Distance<f64> for Hamming {
+///     fn distance(&self, a: &ArrayView1<f64>, b: &ArrayView1<f64>) -> f64 {
+///         a.iter().zip(b).filter(|(x, y)| x != y).count() as f64
+///     }
+/// }
+///
+/// let a = array![1., 2., 3.];
+/// let b = array![1., 0., 0.];
+/// assert_eq!(Hamming.distance(&a.view(), &b.view()), 2.);
+/// ```
+pub trait Distance<A> {
+    /// Returns the distance between `a` and `b`.
+    fn distance(&self, a: &ArrayView1<A>, b: &ArrayView1<A>) -> A;
+}
+
+/// Euclidean distance: `sqrt(∑ᵢ (aᵢ - bᵢ)²)`.
+pub struct Euclidean;
+/// Manhattan distance: `∑ᵢ |aᵢ - bᵢ|`.
+pub struct Manhattan;
+/// Chebyshev distance: `maxᵢ |aᵢ - bᵢ|`.
+pub struct Chebyshev;
+
+fn assert_same_len<A>(a: &ArrayView1<A>, b: &ArrayView1<A>) {
+    assert_eq!(a.len(), b.len(),
+               "Length mismatch: the first array has length {:?}, the second one \
+               has length {:?}.", a.len(), b.len());
+}
+
+impl<A: Float> Distance<A> for Euclidean {
+    /// **Panics** if `a` and `b` do not have the same length.
+    fn distance(&self, a: &ArrayView1<A>, b: &ArrayView1<A>) -> A {
+        assert_same_len(a, b);
+        a.iter()
+            .zip(b)
+            .fold(A::zero(), |acc, (&x, &y)| acc + (x - y) * (x - y))
+            .sqrt()
+    }
+}
+
+impl<A: Float> Distance<A> for Manhattan {
+    /// **Panics** if `a` and `b` do not have the same length.
+    fn distance(&self, a: &ArrayView1<A>, b: &ArrayView1<A>) -> A {
+        assert_same_len(a, b);
+        a.iter()
+            .zip(b)
+            .fold(A::zero(), |acc, (&x, &y)| acc + (x - y).abs())
+    }
+}
+
+impl<A: Float> Distance<A> for Chebyshev {
+    /// **Panics** if `a` and `b` do not have the same length.
+    fn distance(&self, a: &ArrayView1<A>, b: &ArrayView1<A>) -> A {
+        assert_same_len(a, b);
+        a.iter().zip(b).fold(A::zero(), |acc, (&x, &y)| {
+            let d = (x - y).abs();
+            // `Float::max` ignores NaN: propagate it, as the other metrics do.
+            if d.is_nan() || acc.is_nan() { A::nan() } else { acc.max(d) }
+        })
+    }
+}
+
+/// Extension trait for `ArrayBase` providing methods
+/// to compute distances between observations.
+pub trait DistanceExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Returns the matrix of the distances between every pair of rows of a
+    /// 2-dimensional array of observations `M`, according to `metric`.
+    ///
+    /// Let `(n, d)` be the shape of `M`: every row of `M` is a `d`-dimensional
+    /// observation and the returned matrix has shape `(n, n)`, with
+    /// element `[i, j]` equal to `metric.distance(&M.row(i), &M.row(j))`.
+    ///
+    /// The distance is evaluated for each ordered pair of rows, hence
+    /// the result is correct for non-symmetric metrics as well.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::{DistanceExt, distance::Manhattan};
+    ///
+    /// # fn main() {
+    /// let points = array![[0., 0.], [1., 2.], [-1., 1.]];
+    /// let distances = points.pairwise_distances(&Manhattan);
+    /// assert_eq!(
+    ///     distances,
+    ///     array![
+    ///         [0., 3., 2.],
+    ///         [3., 0., 3.],
+    ///         [2., 3., 0.],
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    fn pairwise_distances<M>(&self, metric: &M) -> Array2<A>
+    where
+        M: Distance<A>;
+}
+
+impl<A, S> DistanceExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn pairwise_distances<M>(&self, metric: &M) -> Array2<A>
+    where
+        M: Distance<A>,
+    {
+        let n_observations = self.rows();
+        Array2::from_shape_fn((n_observations, n_observations), |(i, j)| {
+            metric.distance(&self.row(i), &self.row(j))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_builtin_metrics() {
+        let a = array![1., -2., 3.];
+        let b = array![4., 2., 3.];
+        assert_eq!(Euclidean.distance(&a.view(), &b.view()), 5.);
+        assert_eq!(Manhattan.distance(&a.view(), &b.view()), 7.);
+        assert_eq!(Chebyshev.distance(&a.view(), &b.view()), 4.);
+    }
+
+    #[test]
+    fn test_builtin_metrics_propagate_nan() {
+        use std::f64;
+
+        let a = array![1., f64::NAN, 3.];
+        let b = array![4., 2., 3.];
+        assert!(Euclidean.distance(&a.view(), &b.view()).is_nan());
+        assert!(Manhattan.distance(&a.view(), &b.view()).is_nan());
+        assert!(Chebyshev.distance(&a.view(), &b.view()).is_nan());
+        assert!(Chebyshev.distance(&b.view(), &a.view()).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_builtin_metric_with_length_mismatch() {
+        let a = array![1., 2.];
+        let b = array![1., 2., 3.];
+        Euclidean.distance(&a.view(), &b.view());
+    }
+
+    #[test]
+    fn test_pairwise_euclidean_distances() {
+        let points = array![[0., 0.], [3., 4.], [6., 8.]];
+        let distances = points.pairwise_distances(&Euclidean);
+        assert_eq!(
+            distances,
+            array![
+                [0., 5., 10.],
+                [5., 0., 5.],
+                [10., 5., 0.],
+            ]
+        );
+    }
+
+    /// Distance travelled in one direction only: non-negative coordinate
+    /// differences are free, negative ones are counted (non-symmetric).
+    struct Uphill;
+
+    impl Distance<f64> for Uphill {
+        fn distance(&self, a: &ArrayView1<f64>, b: &ArrayView1<f64>) -> f64 {
+            a.iter().zip(b).map(|(x, y)| (y - x).max(0.)).sum()
+        }
+    }
+
+    #[test]
+    fn test_pairwise_distances_with_custom_metric() {
+        let points = array![[0., 1.], [2., 0.]];
+        let distances = points.pairwise_distances(&Uphill);
+        assert_eq!(distances, array![[0., 2.], [1., 0.]]);
+    }
+
+    #[test]
+    fn test_pairwise_distances_without_observations() {
+        let points = Array2::<f64>::zeros((0, 3));
+        assert_eq!(points.pairwise_distances(&Chebyshev).dim(), (0, 0));
+    }
+}
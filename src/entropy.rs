@@ -0,0 +1,385 @@
+use ndarray::prelude::*;
+use ndarray::{Data, RemoveAxis, Zip};
+use num_traits::Float;
+
+/// Extension trait for `ArrayBase` providing methods
+/// to compute information theory quantities (e.g. entropy, softmax, log-sum-exp).
+pub trait EntropyExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the [softmax] of the data along the specified axis:
+    /// each 1-dimensional lane `x` is mapped to
+    ///
+    /// ```text
+    ///                exp(xᵢ)
+    /// softmax(x)ᵢ = ――――――――――
+    ///               ∑ⱼ exp(xⱼ)
+    /// ```
+    ///
+    /// so that its elements are positive and sum to 1.
+    ///
+    /// The maximum of each lane is subtracted before exponentiating,
+    /// to avoid overflowing for large inputs: the result is not affected,
+    /// given that softmax is invariant to adding a constant to all elements of the lane.
+    ///
+    /// A `-∞` element is mapped to 0, as long as its lane contains at least one
+    /// finite element. Lanes containing NaN or `+∞` values, as well as lanes
+    /// made only of `-∞` values, are mapped to lanes of NaN values.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [softmax]: https://en.wikipedia.org/wiki/Softmax_function
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let logits = array![[1000., 1000.], [0., 2f64.ln()]];
+    /// let softmax = logits.softmax_axis(Axis(1));
+    /// assert!(softmax.all_close(&array![[0.5, 0.5], [1. / 3., 2. / 3.]], 1e-12));
+    /// ```
+    fn softmax_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Float;
+
+    /// Return the logarithm of the sum of the exponentials of all the elements
+    /// in the array:
+    ///
+    /// ```text
+    /// log(∑ᵢ exp(xᵢ))
+    /// ```
+    ///
+    /// The maximum `m` of the array is factored out, i.e. the result is
+    /// computed as `m + log(∑ᵢ exp(xᵢ - m))`, to avoid overflowing
+    /// for large inputs.
+    ///
+    /// If the array is empty or all its elements are `-∞`, `-∞` is returned.
+    /// If the array contains `+∞` (and no NaN), `+∞` is returned.
+    /// If the array contains NaN, NaN is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let a = array![1000., 1000.];
+    /// assert!((a.logsumexp() - (1000. + 2f64.ln())).abs() < 1e-12);
+    /// ```
+    fn logsumexp(&self) -> A
+    where
+        A: Float;
+
+    /// Return the logarithm of the sum of the exponentials of the elements
+    /// of each 1-dimensional lane along the specified axis.
+    ///
+    /// See [`logsumexp`](#tymethod.logsumexp) for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn logsumexp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Return the [entropy] `S` of the array values, interpreted
+    /// as the probabilities of a discrete distribution:
+    ///
+    /// ```text
+    ///        n
+    /// S = - ∑ xᵢ ln(xᵢ)
+    ///       i=1
+    /// ```
+    ///
+    /// Terms with `xᵢ = 0` do not contribute to the sum (`0 ln(0) = 0`).
+    /// The natural logarithm is used: the entropy is measured in nats.
+    ///
+    /// The values are **not** checked to be a valid distribution:
+    /// if any of them is negative the result is NaN, while if they do not
+    /// sum to 1 the result is meaningless.
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// [entropy]: https://en.wikipedia.org/wiki/Entropy_(information_theory)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let fair_coin = array![0.5, 0.5];
+    /// assert_eq!(fair_coin.entropy(), Some(2f64.ln()));
+    /// ```
+    fn entropy(&self) -> Option<A>
+    where
+        A: Float;
+
+    /// Return the entropy of each 1-dimensional lane along `axis`,
+    /// each lane being interpreted as a discrete distribution
+    /// (e.g. a batch of distributions stored as the rows of a matrix).
+    ///
+    /// See [`entropy`](#tymethod.entropy) for details.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of `axis` is zero.
+    fn entropy_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis;
+}
+
+impl<A, S, D> EntropyExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn softmax_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut softmax = self.to_owned();
+        for mut lane in softmax.lanes_mut(axis) {
+            let max = nan_max(lane.iter());
+            lane.mapv_inplace(|x| (x - max).exp());
+            let sum = lane.sum();
+            lane.mapv_inplace(|x| x / sum);
+        }
+        softmax
+    }
+
+    fn logsumexp(&self) -> A
+    where
+        A: Float,
+    {
+        logsumexp(self.iter())
+    }
+
+    fn logsumexp_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let mut result = Array::zeros(self.raw_dim().remove_axis(axis));
+        Zip::from(&mut result)
+            .and(self.lanes(axis))
+            .apply(|r, lane| *r = logsumexp(lane.iter()));
+        result
+    }
+
+    fn entropy(&self) -> Option<A>
+    where
+        A: Float,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let entropy = self.fold(A::zero(), |entropy, &p| {
+            if p == A::zero() {
+                entropy
+            } else {
+                entropy - p * p.ln()
+            }
+        });
+        Some(entropy)
+    }
+
+    fn entropy_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "The length of `axis` has to be strictly positive.");
+        let mut result = Array::zeros(self.raw_dim().remove_axis(axis));
+        Zip::from(&mut result)
+            .and(self.lanes(axis))
+            .apply(|r, lane| *r = lane.entropy().unwrap());
+        result
+    }
+}
+
+/// Returns the maximum of the elements, `-∞` if there are none
+/// and NaN if any of them is NaN.
+fn nan_max<'a, A, I>(elements: I) -> A
+where
+    A: 'a + Float,
+    I: Iterator<Item = &'a A>,
+{
+    elements.fold(A::neg_infinity(), |max, &x| {
+        if x.is_nan() || x > max {
+            x
+        } else {
+            max
+        }
+    })
+}
+
+/// Computes `log(∑ᵢ exp(xᵢ))` factoring out the maximum of the elements.
+fn logsumexp<'a, A, I>(elements: I) -> A
+where
+    A: 'a + Float,
+    I: Iterator<Item = &'a A> + Clone,
+{
+    let max = nan_max(elements.clone());
+    if max.is_infinite() {
+        // Either all elements are `-∞` (or there are none), or at least one is `+∞`:
+        // factoring out the maximum would lead to `∞ - ∞ = NaN`.
+        return max;
+    }
+    let sum = elements.fold(A::zero(), |sum, &x| sum + (x - max).exp());
+    max + sum.ln()
+}
+
+#[cfg(test)]
+mod softmax_tests {
+    use super::*;
+    use ndarray::array;
+    use quickcheck::quickcheck;
+    use rand::distributions::Uniform;
+    use ndarray_rand::RandomExt;
+    use std::f64;
+
+    quickcheck! {
+        fn lanes_sum_to_one(bound: f64) -> bool {
+            let bound = bound.abs().min(1e3) + 1.;
+            let a = Array::random((4, 5), Uniform::new(-bound, bound));
+            let softmax = a.softmax_axis(Axis(1));
+            softmax.sum_axis(Axis(1)).all_close(&Array::ones(4), 1e-12)
+        }
+
+        fn invariant_to_constant_shift(shift: f64) -> bool {
+            let shift = shift % 1e3;
+            let a = Array::random((3, 6), Uniform::new(-10., 10.));
+            let shifted = &a + shift;
+            a.softmax_axis(Axis(0)).all_close(&shifted.softmax_axis(Axis(0)), 1e-12)
+        }
+    }
+
+    #[test]
+    fn test_softmax_with_large_logits() {
+        let a = array![1000., 1001.];
+        let softmax = a.softmax_axis(Axis(0));
+        let e = f64::consts::E;
+        assert!(softmax.all_close(&array![1. / (1. + e), e / (1. + e)], 1e-12));
+    }
+
+    #[test]
+    fn test_softmax_with_nan() {
+        let a = array![[1., f64::NAN], [1., 1.]];
+        let softmax = a.softmax_axis(Axis(1));
+        assert!(softmax.row(0).iter().all(|x| x.is_nan()));
+        assert_eq!(softmax.row(1), array![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_softmax_with_infinite_values() {
+        let a = array![
+            [1., f64::NEG_INFINITY, 1.],
+            [1., f64::INFINITY, 1.],
+            [f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY],
+        ];
+        let softmax = a.softmax_axis(Axis(1));
+        assert_eq!(softmax.row(0), array![0.5, 0., 0.5]);
+        assert!(softmax.row(1).iter().all(|x| x.is_nan()));
+        assert!(softmax.row(2).iter().all(|x| x.is_nan()));
+    }
+}
+
+#[cfg(test)]
+mod logsumexp_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_logsumexp_of_zeros() {
+        let a = array![0., 0., 0.];
+        assert!((a.logsumexp() - 3f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logsumexp_does_not_overflow() {
+        let a = array![1000., 1001.];
+        let expected = 1001. + (1. + (-1f64).exp()).ln();
+        assert!((a.logsumexp() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logsumexp_with_infinities() {
+        assert_eq!(array![f64::NEG_INFINITY, f64::NEG_INFINITY].logsumexp(), f64::NEG_INFINITY);
+        assert_eq!(array![1., f64::INFINITY].logsumexp(), f64::INFINITY);
+        assert!(array![f64::INFINITY, f64::NAN].logsumexp().is_nan());
+    }
+
+    #[test]
+    fn test_logsumexp_of_empty_array() {
+        let a: Array1<f64> = array![];
+        assert_eq!(a.logsumexp(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_logsumexp_axis() {
+        let a = array![[0., 0.], [1000., 1000.], [f64::NEG_INFINITY, 0.]];
+        let expected = array![2f64.ln(), 1000. + 2f64.ln(), 0.];
+        assert!(a.logsumexp_axis(Axis(1)).all_close(&expected, 1e-12));
+    }
+
+    #[test]
+    fn test_logsumexp_axis_of_empty_lanes() {
+        let a: Array2<f64> = Array2::zeros((3, 0));
+        assert_eq!(a.logsumexp_axis(Axis(1)), Array1::from_elem(3, f64::NEG_INFINITY));
+    }
+}
+
+#[cfg(test)]
+mod entropy_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_entropy() {
+        let uniform = Array1::from_elem(4, 0.25);
+        assert!((uniform.entropy().unwrap() - 4f64.ln()).abs() < 1e-12);
+        let certain = array![0., 1., 0.];
+        assert_eq!(certain.entropy(), Some(0.));
+        let a = array![0.1, 0.2, 0.7];
+        let expected = -(0.1 * 0.1f64.ln() + 0.2 * 0.2f64.ln() + 0.7 * 0.7f64.ln());
+        assert!((a.entropy().unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_entropy_of_empty_array() {
+        let a: Array1<f64> = array![];
+        assert_eq!(a.entropy(), None);
+    }
+
+    #[test]
+    fn test_entropy_with_negative_values() {
+        assert!(array![-0.5, 1.5].entropy().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_entropy_axis() {
+        let distributions = array![[0.5, 0.5, 0.], [0.1, 0.2, 0.7], [0., 0., 1.]];
+        let entropies = distributions.entropy_axis(Axis(1));
+        for (row, &entropy) in distributions.genrows().into_iter().zip(entropies.iter()) {
+            assert_eq!(row.entropy(), Some(entropy));
+        }
+        assert!(entropies.all_close(&array![2f64.ln(), 0.8018185525433373, 0.], 1e-12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_entropy_axis_with_zero_length_axis() {
+        Array2::<f64>::zeros((2, 0)).entropy_axis(Axis(1));
+    }
+}
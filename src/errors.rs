@@ -0,0 +1,80 @@
+//! Custom errors returned from our methods and functions.
+use std::error::Error;
+use std::fmt;
+
+/// An error that indicates that the input array was empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmptyInput;
+
+impl fmt::Display for EmptyInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Empty input.")
+    }
+}
+
+impl Error for EmptyInput {
+    fn description(&self) -> &str {
+        "Empty input."
+    }
+}
+
+/// An error involving multiple input arrays.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MultiInputError {
+    /// One or more of the arrays were empty.
+    EmptyInput,
+    /// The arrays did not have the same shape.
+    ShapeMismatch {
+        /// Shape of the first array.
+        first: Vec<usize>,
+        /// Shape of the second array.
+        second: Vec<usize>,
+    },
+}
+
+impl fmt::Display for MultiInputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MultiInputError::EmptyInput => write!(f, "Empty input."),
+            MultiInputError::ShapeMismatch { ref first, ref second } => write!(
+                f,
+                "Array shapes do not match: the first array has shape {:?}, \
+                 the second one has shape {:?}.",
+                first, second
+            ),
+        }
+    }
+}
+
+impl Error for MultiInputError {
+    fn description(&self) -> &str {
+        match *self {
+            MultiInputError::EmptyInput => "Empty input.",
+            MultiInputError::ShapeMismatch { .. } => "Array shapes do not match.",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shape_mismatch_message() {
+        let error = MultiInputError::ShapeMismatch {
+            first: vec![2, 3],
+            second: vec![4],
+        };
+        assert_eq!(
+            error.to_string(),
+            "Array shapes do not match: the first array has shape [2, 3], \
+             the second one has shape [4]."
+        );
+    }
+
+    #[test]
+    fn test_empty_input_message() {
+        assert_eq!(MultiInputError::EmptyInput.to_string(), "Empty input.");
+        assert_eq!(EmptyInput.to_string(), "Empty input.");
+    }
+}
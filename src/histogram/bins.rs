@@ -1,4 +1,5 @@
 use ndarray::prelude::*;
+use std::fmt;
 use std::ops::{Index, Range};
 
 /// `Edges` is a sorted collection of `A` elements used
@@ -197,6 +198,33 @@ impl<A: Ord> Edges<A> {
     }
 }
 
+impl<A: Ord + fmt::Display> fmt::Display for Edges<A> {
+    /// Formats the edges as a comma-separated list enclosed in square brackets.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// use ndarray_stats::histogram::Edges;
+    ///
+    /// let edges = Edges::from(vec![0, 5, 3]);
+    /// assert_eq!(
+    ///     format!("{}", edges),
+    ///     "[0, 3, 5]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, edge) in self.edges.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", edge)?;
+        }
+        write!(f, "]")
+    }
+}
+
 /// `Bins` is a sorted collection of non-overlapping
 /// 1-dimensional intervals.
 ///
@@ -355,6 +383,26 @@ impl<A: Ord> Bins<A> {
     }
 }
 
+impl<A: Ord + fmt::Display> fmt::Display for Bins<A> {
+    /// Formats the bins as the list of their edges.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// use ndarray_stats::histogram::{Edges, Bins};
+    ///
+    /// let bins = Bins::new(Edges::from(vec![1, 5, 10]));
+    /// assert_eq!(
+    ///     format!("{}", bins),
+    ///     "[1, 5, 10]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.edges)
+    }
+}
+
 #[cfg(test)]
 mod edges_tests {
     use super::*;
@@ -1,8 +1,9 @@
+use errors::MultiInputError;
 use std::error;
 use std::fmt;
 
 /// Error to denote that no bin has been found for a certain observation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BinNotFound;
 
 impl fmt::Display for BinNotFound {
@@ -21,3 +22,67 @@ impl error::Error for BinNotFound {
         None
     }
 }
+
+/// Error to denote that some observations fell outside the grid
+/// and no bin has been found for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObservationsOutOfGrid {
+    /// Number of observations outside the grid.
+    pub n_observations: usize,
+}
+
+impl fmt::Display for ObservationsOutOfGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No bin has been found for {} observations.", self.n_observations)
+    }
+}
+
+impl error::Error for ObservationsOutOfGrid {
+    fn description(&self) -> &str {
+        "No bin has been found for some observations."
+    }
+}
+
+/// Error returned by [`confusion_matrix`].
+///
+/// [`confusion_matrix`]: ../trait.Histogram1dExt.html#tymethod.confusion_matrix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfusionMatrixError {
+    /// The arrays of true and predicted labels did not have the same length.
+    MultiInput(MultiInputError),
+    /// A label was greater than or equal to the number of classes.
+    BinNotFound(BinNotFound),
+}
+
+impl fmt::Display for ConfusionMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfusionMatrixError::MultiInput(ref err) => write!(f, "{}", err),
+            ConfusionMatrixError::BinNotFound(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for ConfusionMatrixError {
+    fn description(&self) -> &str {
+        match *self {
+            ConfusionMatrixError::MultiInput(MultiInputError::EmptyInput) => "Empty input.",
+            ConfusionMatrixError::MultiInput(MultiInputError::ShapeMismatch { .. }) => {
+                "Array shapes do not match."
+            }
+            ConfusionMatrixError::BinNotFound(_) => "No bin has been found.",
+        }
+    }
+}
+
+impl From<MultiInputError> for ConfusionMatrixError {
+    fn from(err: MultiInputError) -> Self {
+        ConfusionMatrixError::MultiInput(err)
+    }
+}
+
+impl From<BinNotFound> for ConfusionMatrixError {
+    fn from(err: BinNotFound) -> Self {
+        ConfusionMatrixError::BinNotFound(err)
+    }
+}
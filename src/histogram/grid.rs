@@ -1,6 +1,8 @@
 use super::bins::Bins;
 use super::strategies::BinsBuildingStrategy;
+use std::fmt;
 use std::ops::Range;
+use axis_lanes;
 use itertools::izip;
 use ndarray::{ArrayBase, Data, Ix1, Ix2, Axis};
 
@@ -94,6 +96,24 @@ impl<A: Ord> Grid<A> {
     }
 
     /// Returns the number of bins along each coordinate axis.
+    ///
+    /// It is the shape of the counts matrix of a [`Histogram`] built on this grid.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// use ndarray_stats::histogram::{Bins, Edges, Grid};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 1, 2, 3])),
+    ///     Bins::new(Edges::from(vec![0, 5, 10, 15, 20])),
+    /// ]);
+    /// assert_eq!(grid.ndim(), 2);
+    /// assert_eq!(grid.shape(), vec![3, 4]);
+    /// ```
+    ///
+    /// [`Histogram`]: struct.Histogram.html
     pub fn shape(&self) -> Vec<usize> {
         self.projections.iter().map(|e| e.len()).collect()
     }
@@ -141,6 +161,34 @@ impl<A: Ord + Clone> Grid<A> {
     }
 }
 
+impl<A: Ord + fmt::Display> fmt::Display for Grid<A> {
+    /// Formats the grid listing, for each coordinate axis, the edges
+    /// of its projection.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// use ndarray_stats::histogram::{Edges, Bins, Grid};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 1, 2])),
+    ///     Bins::new(Edges::from(vec![0, 5, 10])),
+    /// ]);
+    /// assert_eq!(
+    ///     format!("{}", grid),
+    ///     "Grid (2 dimensions):\n  axis 0: [0, 1, 2]\n  axis 1: [0, 5, 10]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Grid ({} dimensions):", self.ndim())?;
+        for (axis, bins) in self.projections.iter().enumerate() {
+            write!(f, "\n  axis {}: {}", axis, bins)?;
+        }
+        Ok(())
+    }
+}
+
 /// `GridBuilder`, given a [`strategy`] and some observations, returns a [`Grid`]
 /// instance for [`histogram`] computation.
 ///
@@ -166,8 +214,7 @@ where
     where
         S: Data<Elem=A>,
     {
-        let bin_builders = array
-            .axis_iter(Axis(1))
+        let bin_builders = axis_lanes(array, Axis(0))
             .map(|data| B::from_array(&data))
             .collect();
         Self { bin_builders }
@@ -184,3 +231,56 @@ where
         Grid::from(projections)
     }
 }
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+    use super::super::{Edges, Histogram};
+    use super::super::strategies::Sqrt;
+    use ndarray::array;
+
+    #[test]
+    fn identically_built_grids_are_equal() {
+        let observations = array![
+            [1, 10],
+            [4, 20],
+            [5, 30],
+            [9, 40],
+        ];
+        let first = GridBuilder::<Sqrt<i32>>::from_array(&observations).build();
+        let second = GridBuilder::<Sqrt<i32>>::from_array(&observations).build();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn grids_with_different_edges_are_not_equal() {
+        let first = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+        let second = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn shape_and_ndim() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 1, 2, 3])),
+            Bins::new(Edges::from(vec![-4, -2, 0, 2, 4])),
+        ]);
+        assert_eq!(grid.ndim(), 2);
+        assert_eq!(grid.shape(), vec![3, 4]);
+
+        let histogram = Histogram::new(grid.clone());
+        assert_eq!(histogram.counts().shape(), &grid.shape()[..]);
+    }
+
+    #[test]
+    fn display_lists_edges_for_each_axis() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![-1, 0, 1])),
+            Bins::new(Edges::from(vec![0, 10, 20, 30])),
+        ]);
+        assert_eq!(
+            format!("{}", grid),
+            "Grid (2 dimensions):\n  axis 0: [-1, 0, 1]\n  axis 1: [0, 10, 20, 30]"
+        );
+    }
+}
@@ -1,9 +1,16 @@
 use ndarray::prelude::*;
-use ndarray::Data;
+use ndarray::{Data, Zip};
+use num_traits::{Float, FromPrimitive};
+use std::fmt;
+use errors::MultiInputError;
+use sort::partition_point;
+use Sort1dExt;
+use super::bins::Bins;
 use super::grid::Grid;
-use super::errors::BinNotFound;
+use super::errors::{BinNotFound, ConfusionMatrixError, ObservationsOutOfGrid};
 
 /// Histogram data structure.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Histogram<A: Ord> {
     counts: ArrayD<usize>,
     grid: Grid<A>,
@@ -74,12 +81,75 @@ impl<A: Ord> Histogram<A> {
         self.counts.view()
     }
 
+    /// Consumes the histogram, returning its counts matrix
+    /// without copying it.
+    pub fn into_counts(self) -> ArrayD<usize> {
+        self.counts
+    }
+
     /// Borrows an immutable reference to the histogram grid.
     pub fn grid(&self) -> &Grid<A> {
         &self.grid
     }
 }
 
+impl<A: Ord + Clone> Histogram<A> {
+    /// Returns, for each coordinate axis, the `(low, high)` edges of the
+    /// cell with the given `n`-dimensional index: the cell covers
+    /// `[low, high)` on each axis.
+    ///
+    /// Combined with the index of the largest element of [`counts`]
+    /// it gives the modal cell of the histogram.
+    ///
+    /// **Panics** if `index.len()` is different from `self.ndim()` or if
+    /// the index is out of bounds on any coordinate axis.
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate ndarray_stats;
+    /// extern crate ndarray;
+    /// use ndarray::array;
+    /// use ndarray_stats::HistogramExt;
+    /// use ndarray_stats::histogram::{Edges, Bins, Grid};
+    ///
+    /// let grid = Grid::from(vec![
+    ///     Bins::new(Edges::from(vec![0, 5, 10])),
+    ///     Bins::new(Edges::from(vec![0, 1, 2, 3])),
+    /// ]);
+    /// let histogram = array![[1, 2], [7, 0], [8, 2]].histogram(grid);
+    /// assert_eq!(histogram.bin_edges(&[1, 2]), vec![(5, 10), (2, 3)]);
+    /// ```
+    ///
+    /// [`counts`]: #method.counts
+    pub fn bin_edges(&self, index: &[usize]) -> Vec<(A, A)> {
+        let shape = self.counts.shape();
+        assert_eq!(index.len(), shape.len(),
+                   "Dimension mismatch: the index has {:?} dimensions, the histogram \
+                   has {:?} dimensions.", index.len(), shape.len());
+        assert!(index.iter().zip(shape).all(|(i, n)| i < n),
+                "Index out of bounds: the index is {:?}, the histogram has shape {:?}.",
+                index, shape);
+        self.grid
+            .index(index)
+            .into_iter()
+            .map(|range| (range.start, range.end))
+            .collect()
+    }
+}
+
+impl<A: Ord + fmt::Display> fmt::Display for Histogram<A> {
+    /// Formats the histogram listing the edges of its grid along each
+    /// coordinate axis, followed by the total number of observations
+    /// that have been counted.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Histogram ({} dimensions):", self.ndim())?;
+        for (axis, bins) in self.grid.projections().iter().enumerate() {
+            write!(f, "\n  axis {}: {}", axis, bins)?;
+        }
+        write!(f, "\n  total count: {}", self.counts.sum())
+    }
+}
+
 /// Extension trait for `ArrayBase` providing methods to compute histograms.
 pub trait HistogramExt<A, S>
     where
@@ -97,6 +167,7 @@ pub trait HistogramExt<A, S>
     /// 4-dimensional space.
     ///
     /// Important: points outside the grid are ignored!
+    /// Use [`histogram_checked`] if they should be reported instead.
     ///
     /// **Panics** if `d` is different from `grid.ndim()`.
     ///
@@ -147,6 +218,40 @@ pub trait HistogramExt<A, S>
     fn histogram(&self, grid: Grid<A>) -> Histogram<A>
         where
             A: Ord;
+
+    /// Returns the [histogram](https://en.wikipedia.org/wiki/Histogram)
+    /// for a 2-dimensional array of points `M`, as [`histogram`] does,
+    /// failing if any of the points falls outside the grid.
+    ///
+    /// If there are points outside the grid, an error is returned
+    /// reporting how many of them have been found.
+    ///
+    /// **Panics** if `d` is different from `grid.ndim()`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::{
+    ///     HistogramExt,
+    ///     histogram::{Grid, Edges, Bins},
+    /// };
+    ///
+    /// # fn main() {
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 5, 10]))]);
+    /// let observations = array![[1], [7], [12], [-3]];
+    ///
+    /// let error = observations.histogram_checked(grid).unwrap_err();
+    /// assert_eq!(error.n_observations, 2);
+    /// # }
+    /// ```
+    ///
+    /// [`histogram`]: #tymethod.histogram
+    fn histogram_checked(&self, grid: Grid<A>) -> Result<Histogram<A>, ObservationsOutOfGrid>
+        where
+            A: Ord;
 }
 
 impl<A, S> HistogramExt<A, S> for ArrayBase<S, Ix2>
@@ -162,4 +267,862 @@ impl<A, S> HistogramExt<A, S> for ArrayBase<S, Ix2>
         }
         histogram
     }
+
+    fn histogram_checked(&self, grid: Grid<A>) -> Result<Histogram<A>, ObservationsOutOfGrid>
+    {
+        let mut histogram = Histogram::new(grid);
+        let mut n_observations = 0;
+        for point in self.axis_iter(Axis(0)) {
+            if histogram.add_observation(&point).is_err() {
+                n_observations += 1;
+            }
+        }
+        if n_observations == 0 {
+            Ok(histogram)
+        } else {
+            Err(ObservationsOutOfGrid { n_observations })
+        }
+    }
+}
+
+/// Extension trait for 1-dimensional `ArrayBase` providing methods
+/// to discretize and bin their elements.
+pub trait Histogram1dExt<A, S>
+    where
+        S: Data<Elem = A>,
+{
+    /// Returns, for each element `x` of the array, the index of the bin
+    /// containing `x` among the ones delimited by the monotonic sequence `edges`
+    /// (as NumPy's `digitize`).
+    ///
+    /// If `edges` is increasing, the index `i` returned for `x` satisfies
+    /// - `edges[i-1] <= x < edges[i]`, if `right` is `false`;
+    /// - `edges[i-1] < x <= edges[i]`, if `right` is `true`.
+    ///
+    /// If `edges` is decreasing, the index `i` returned for `x` satisfies
+    /// - `edges[i-1] > x >= edges[i]`, if `right` is `false`;
+    /// - `edges[i-1] >= x > edges[i]`, if `right` is `true`.
+    ///
+    /// Elements beyond the first edge are mapped to `0`, while elements
+    /// beyond the last edge are mapped to `edges.len()`.
+    ///
+    /// **Panics** if `edges` is neither increasing nor decreasing.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::Histogram1dExt;
+    ///
+    /// # fn main() {
+    /// let x = array![1.2, 10.0, 12.4, 15.5, 20.];
+    /// let edges = array![0., 5., 10., 15., 20.];
+    /// assert_eq!(x.digitize(&edges, false), array![1, 3, 3, 4, 5]);
+    /// assert_eq!(x.digitize(&edges, true), array![1, 2, 3, 4, 4]);
+    /// # }
+    /// ```
+    fn digitize<S2>(&self, edges: &ArrayBase<S2, Ix1>, right: bool) -> Array1<usize>
+        where
+            S2: Data<Elem = A>,
+            A: PartialOrd;
+
+    /// Returns the number of occurrences of each class label in the array,
+    /// for labels in `0..n_classes`: the `i`-th element of the returned array
+    /// is the number of elements equal to `i`.
+    ///
+    /// The returned array has always length `n_classes`, even if some classes
+    /// do not appear in the array.
+    ///
+    /// It returns `Err(BinNotFound)` if any label is greater than or equal to `n_classes`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::Histogram1dExt;
+    ///
+    /// # fn main() {
+    /// let labels: ndarray::Array1<usize> = array![0, 2, 2, 1, 2];
+    /// assert_eq!(labels.class_histogram(4).unwrap(), array![1, 1, 3, 0]);
+    /// assert!(labels.class_histogram(2).is_err());
+    /// # }
+    /// ```
+    fn class_histogram(&self, n_classes: usize) -> Result<Array1<usize>, BinNotFound>
+        where
+            A: Clone + Into<usize>;
+
+    /// Returns the confusion matrix of a classifier, using `self` as the
+    /// array of true class labels and `predictions` as the array of
+    /// predicted class labels, for labels in `0..n_classes`.
+    ///
+    /// The returned matrix has shape `(n_classes, n_classes)` and its
+    /// element `[i, j]` is the number of samples with true label `i`
+    /// and predicted label `j`: it is the 2-dimensional analogue of
+    /// [`class_histogram`](#tymethod.class_histogram).
+    ///
+    /// It returns `Err(ConfusionMatrixError::MultiInput(MultiInputError::ShapeMismatch))`
+    /// if `self` and `predictions` do not have the same length, and
+    /// `Err(ConfusionMatrixError::BinNotFound)` if any label, true or predicted,
+    /// is greater than or equal to `n_classes`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::Histogram1dExt;
+    ///
+    /// # fn main() {
+    /// let y_true: ndarray::Array1<usize> = array![0, 1, 1, 2];
+    /// let y_pred: ndarray::Array1<usize> = array![0, 2, 1, 2];
+    /// assert_eq!(
+    ///     y_true.confusion_matrix(&y_pred, 3).unwrap(),
+    ///     array![
+    ///         [1, 0, 0],
+    ///         [0, 1, 1],
+    ///         [0, 0, 1],
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    fn confusion_matrix<S2>(
+        &self,
+        predictions: &ArrayBase<S2, Ix1>,
+        n_classes: usize,
+    ) -> Result<Array2<usize>, ConfusionMatrixError>
+        where
+            S2: Data<Elem = A>,
+            A: Clone + Into<usize>;
+
+    /// Returns the histogram of the array using `n_bins` bins of equal width
+    /// spanning the interval `[min, max]`, where `min` and `max` are the
+    /// minimum and the maximum of the array (as NumPy's `histogram(a, bins=n_bins)`).
+    ///
+    /// All bins are left inclusive and right exclusive, apart from the last one
+    /// which is closed in order to include the maximum.
+    /// If all the elements of the array are equal to `x`, the bins
+    /// span the interval `[x - 0.5, x + 0.5]`.
+    ///
+    /// NaN values are ignored.
+    ///
+    /// **Panics** if `n_bins` is zero or if the array contains infinite values.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::Histogram1dExt;
+    ///
+    /// # fn main() {
+    /// let a = array![1., 2., 2., 4.];
+    /// // the bins are [1, 2), [2, 3) and [3, 4]
+    /// assert_eq!(a.histogram_uniform(3), array![1, 2, 1]);
+    /// # }
+    /// ```
+    fn histogram_uniform(&self, n_bins: usize) -> Array1<usize>
+        where
+            A: Float + FromPrimitive;
+
+    /// Returns Pearson's [chi-square statistic] between the observed
+    /// frequencies in `self` and the `expected` frequencies:
+    ///
+    /// ```text
+    ///       n   (oᵢ - eᵢ)²
+    /// χ² =  ∑   ――――――――――
+    ///      i=1      eᵢ
+    /// ```
+    ///
+    /// Bins with zero expected frequency are handled as follows:
+    /// - if the observed frequency is zero as well, the bin is skipped;
+    /// - otherwise the statistic is `+∞` (the observations are impossible
+    ///   under the expected distribution).
+    ///
+    /// The following **errors** may be returned:
+    /// - `MultiInputError::EmptyInput` if `self` is empty;
+    /// - `MultiInputError::ShapeMismatch` if `self` and `expected` do not
+    ///   have the same length.
+    ///
+    /// [chi-square statistic]: https://en.wikipedia.org/wiki/Pearson%27s_chi-squared_test
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray_stats::Histogram1dExt;
+    ///
+    /// # fn main() {
+    /// let observed = array![18., 22., 20.];
+    /// let expected = array![20., 20., 20.];
+    /// assert_eq!(observed.chi_square(&expected), Ok(0.4));
+    /// # }
+    /// ```
+    fn chi_square<S2>(&self, expected: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+        where
+            S2: Data<Elem = A>,
+            A: Float;
+}
+
+impl<A, S> Histogram1dExt<A, S> for ArrayBase<S, Ix1>
+    where
+        S: Data<Elem = A>,
+{
+    fn digitize<S2>(&self, edges: &ArrayBase<S2, Ix1>, right: bool) -> Array1<usize>
+        where
+            S2: Data<Elem = A>,
+            A: PartialOrd,
+    {
+        let n_edges = edges.len();
+        let increasing = edges.is_sorted_by(|a, b| a <= b);
+        let decreasing = edges.is_sorted_by(|a, b| a >= b);
+        assert!(increasing || decreasing,
+                "`edges` must be monotonically increasing or decreasing.");
+        self.map(|x| {
+            if increasing {
+                if right {
+                    partition_point(n_edges, |i| edges[i] < *x)
+                } else {
+                    partition_point(n_edges, |i| edges[i] <= *x)
+                }
+            } else if right {
+                partition_point(n_edges, |i| edges[i] >= *x)
+            } else {
+                partition_point(n_edges, |i| edges[i] > *x)
+            }
+        })
+    }
+
+    fn class_histogram(&self, n_classes: usize) -> Result<Array1<usize>, BinNotFound>
+        where
+            A: Clone + Into<usize>,
+    {
+        class_counts(self.iter(), n_classes)
+    }
+
+    fn confusion_matrix<S2>(
+        &self,
+        predictions: &ArrayBase<S2, Ix1>,
+        n_classes: usize,
+    ) -> Result<Array2<usize>, ConfusionMatrixError>
+        where
+            S2: Data<Elem = A>,
+            A: Clone + Into<usize>,
+    {
+        if self.len() != predictions.len() {
+            return Err(MultiInputError::ShapeMismatch {
+                first: self.shape().to_vec(),
+                second: predictions.shape().to_vec(),
+            }.into());
+        }
+        let mut counts = Array2::zeros((n_classes, n_classes));
+        for (true_label, predicted_label) in self.iter().zip(predictions) {
+            let index = (true_label.clone().into(), predicted_label.clone().into());
+            match counts.get_mut(index) {
+                Some(count) => *count += 1,
+                None => return Err(BinNotFound.into()),
+            }
+        }
+        Ok(counts)
+    }
+
+    fn histogram_uniform(&self, n_bins: usize) -> Array1<usize>
+        where
+            A: Float + FromPrimitive,
+    {
+        assert!(n_bins > 0, "The number of bins has to be strictly positive.");
+        let mut counts = Array1::zeros(n_bins);
+        let (min, max) = self.iter()
+            .filter(|x| !x.is_nan())
+            .fold(None, |range, &x| match range {
+                None => Some((x, x)),
+                Some((min, max)) => Some((min.min(x), max.max(x))),
+            })
+            .unwrap_or((A::zero(), A::one()));
+        assert!(min.is_finite() && max.is_finite(),
+                "The elements of the array have to be finite to build uniform bins.");
+        let (min, max) = if min == max {
+            let half = A::from_f64(0.5).unwrap();
+            (min - half, max + half)
+        } else {
+            (min, max)
+        };
+        let n = A::from_usize(n_bins).unwrap();
+        // Halving the elements first keeps `max - min` finite even when the
+        // extremes are close to the largest representable values.
+        let two = A::from_u8(2).unwrap();
+        let half_width = max / two - min / two;
+        for &x in self.iter().filter(|x| !x.is_nan()) {
+            let index = ((x / two - min / two) / half_width * n).floor().to_usize().unwrap();
+            // the last bin is closed: the maximum belongs to it
+            counts[index.min(n_bins - 1)] += 1;
+        }
+        counts
+    }
+
+    fn chi_square<S2>(&self, expected: &ArrayBase<S2, Ix1>) -> Result<A, MultiInputError>
+        where
+            S2: Data<Elem = A>,
+            A: Float,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.len() != expected.len() {
+            return Err(MultiInputError::ShapeMismatch {
+                first: self.shape().to_vec(),
+                second: expected.shape().to_vec(),
+            });
+        }
+        let statistic = self.iter().zip(expected).fold(A::zero(), |statistic, (&o, &e)| {
+            if e == A::zero() {
+                if o == A::zero() {
+                    statistic
+                } else {
+                    statistic + A::infinity()
+                }
+            } else {
+                statistic + (o - e) * (o - e) / e
+            }
+        });
+        Ok(statistic)
+    }
+}
+
+/// Returns the number of occurrences of each class label in `labels`,
+/// for labels in `0..n_classes`, or `Err(BinNotFound)` if any label
+/// is greater than or equal to `n_classes`.
+///
+/// It backs [`class_histogram`](trait.Histogram1dExt.html#tymethod.class_histogram)
+/// and can be used on the elements of arrays of any dimension.
+pub(crate) fn class_counts<'a, A, I>(
+    labels: I,
+    n_classes: usize,
+) -> Result<Array1<usize>, BinNotFound>
+    where
+        A: 'a + Clone + Into<usize>,
+        I: IntoIterator<Item = &'a A>,
+{
+    let mut counts = Array1::zeros(n_classes);
+    for label in labels {
+        let label: usize = label.clone().into();
+        match counts.get_mut(label) {
+            Some(count) => *count += 1,
+            None => return Err(BinNotFound),
+        }
+    }
+    Ok(counts)
+}
+
+/// Extension trait for `ArrayBase` providing methods to compute
+/// histograms of each 1-dimensional lane along an axis.
+pub trait HistogramAxisExt<A, S, D>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+{
+    /// Returns the histograms of the 1-dimensional lanes along `axis`,
+    /// computed independently for each lane with the same `bins`.
+    ///
+    /// The returned array has the same shape of `self`, except along `axis`
+    /// where its length is `bins.len()`: the `i`-th element of a lane along
+    /// `axis` is the number of elements of the corresponding lane of `self`
+    /// falling in the `i`-th bin.
+    ///
+    /// Important: elements outside `bins` are ignored!
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray::Axis;
+    /// use ndarray_stats::histogram::{Bins, Edges, HistogramAxisExt};
+    ///
+    /// # fn main() {
+    /// let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+    /// let a = array![[1, 6, 2], [7, 8, 9]];
+    /// assert_eq!(a.histogram_axis(Axis(1), &bins), array![[2, 1], [0, 3]]);
+    /// # }
+    /// ```
+    fn histogram_axis(&self, axis: Axis, bins: &Bins<A>) -> Array<usize, D>
+        where
+            A: Ord;
+
+    /// Returns the number of occurrences of each class label, for labels in
+    /// `0..n_classes`, in each 1-dimensional lane along `axis`.
+    ///
+    /// It is the per-lane version of [`class_histogram`]: the returned array
+    /// has the same shape of `self`, except along `axis` where its length is
+    /// `n_classes`.
+    ///
+    /// It returns `Err(BinNotFound)` if any label is greater than or equal to `n_classes`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// extern crate ndarray_stats;
+    /// #[macro_use(array)]
+    /// extern crate ndarray;
+    /// use ndarray::Axis;
+    /// use ndarray_stats::HistogramAxisExt;
+    ///
+    /// # fn main() {
+    /// // Class labels of 4 samples (rows) for 2 features (columns)
+    /// let labels: ndarray::Array2<usize> = array![[0, 2], [1, 2], [0, 0], [0, 2]];
+    /// let counts = labels.bincount_axis(Axis(0), 3).unwrap();
+    /// assert_eq!(counts, array![[3, 1], [1, 0], [0, 3]]);
+    /// # }
+    /// ```
+    ///
+    /// [`class_histogram`]: trait.Histogram1dExt.html#tymethod.class_histogram
+    fn bincount_axis(&self, axis: Axis, n_classes: usize) -> Result<Array<usize, D>, BinNotFound>
+        where
+            A: Clone + Into<usize>;
+}
+
+impl<A, S, D> HistogramAxisExt<A, S, D> for ArrayBase<S, D>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+{
+    fn histogram_axis(&self, axis: Axis, bins: &Bins<A>) -> Array<usize, D>
+        where
+            A: Ord,
+    {
+        let mut shape = self.raw_dim();
+        shape[axis.index()] = bins.len();
+        let mut histograms = Array::zeros(shape);
+        Zip::from(histograms.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .apply(|mut counts, lane| {
+                for x in lane.iter() {
+                    if let Some(i) = bins.index_of(x) {
+                        counts[i] += 1;
+                    }
+                }
+            });
+        histograms
+    }
+
+    fn bincount_axis(&self, axis: Axis, n_classes: usize) -> Result<Array<usize, D>, BinNotFound>
+        where
+            A: Clone + Into<usize>,
+    {
+        if self.iter().any(|label| label.clone().into() >= n_classes) {
+            return Err(BinNotFound);
+        }
+        let mut shape = self.raw_dim();
+        shape[axis.index()] = n_classes;
+        let mut counts = Array::zeros(shape);
+        Zip::from(counts.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .apply(|mut counts, lane| {
+                for label in lane.iter() {
+                    counts[label.clone().into()] += 1;
+                }
+            });
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod histogram_tests {
+    use super::*;
+    use super::super::{Edges, Bins};
+    use ndarray::array;
+
+    #[test]
+    fn display_shows_edges_and_total_count() {
+        let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+        let grid = Grid::from(vec![bins.clone(), bins]);
+        let observations = array![[1, 2], [6, 7], [3, 8], [20, 1]];
+        let histogram = observations.histogram(grid);
+        assert_eq!(
+            format!("{}", histogram),
+            "Histogram (2 dimensions):\n  axis 0: [0, 5, 10]\n  axis 1: [0, 5, 10]\n  total count: 3"
+        );
+    }
+
+    #[test]
+    fn histogram_with_integer_edges() {
+        let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+        let grid = Grid::from(vec![bins]);
+        let observations = Array::from_iter(0..=9).insert_axis(Axis(1));
+        let histogram = observations.histogram(grid);
+        assert_eq!(histogram.counts(), array![5, 5].into_dyn());
+    }
+
+    #[test]
+    fn histogram_checked_counts_points_outside_the_grid() {
+        let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+        let grid = Grid::from(vec![bins.clone(), bins]);
+        let observations = array![[1, 2], [6, 7], [10, 8], [3, -1], [20, 20], [4, 4]];
+        let error = observations.histogram_checked(grid.clone()).unwrap_err();
+        assert_eq!(error, ObservationsOutOfGrid { n_observations: 3 });
+        // the lenient version keeps the points inside the grid
+        assert_eq!(observations.histogram(grid).counts().sum(), 3);
+    }
+
+    #[test]
+    fn histogram_checked_succeeds_if_all_points_are_inside_the_grid() {
+        let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+        let grid = Grid::from(vec![bins]);
+        let observations = array![[1], [6], [9]];
+        let histogram = observations.histogram_checked(grid.clone()).unwrap();
+        assert_eq!(histogram, observations.histogram(grid));
+    }
+
+    #[test]
+    fn counts_view_and_into_counts_share_the_same_data() {
+        let bins = Bins::new(Edges::from(vec![0, 5, 10]));
+        let grid = Grid::from(vec![bins.clone(), bins]);
+        let observations = array![[1, 2], [6, 7], [3, 8], [1, 1]];
+        let histogram = observations.histogram(grid);
+        let expected = array![[2, 1], [0, 1]].into_dyn();
+        assert_eq!(histogram.counts(), expected);
+
+        let pointer = histogram.counts().as_ptr();
+        let counts = histogram.into_counts();
+        assert_eq!(counts.as_ptr(), pointer);
+        assert_eq!(counts, expected);
+    }
+
+    #[test]
+    fn bin_edges_of_a_cell_in_a_2d_histogram() {
+        let grid = Grid::from(vec![
+            Bins::new(Edges::from(vec![0, 10, 20, 30])),
+            Bins::new(Edges::from(vec![-5, 0, 5])),
+        ]);
+        let observations = array![[12, -1], [15, -3], [25, 4], [1, 1]];
+        let histogram = observations.histogram(grid);
+        assert_eq!(histogram.bin_edges(&[1, 0]), vec![(10, 20), (-5, 0)]);
+        assert_eq!(histogram.bin_edges(&[2, 1]), vec![(20, 30), (0, 5)]);
+        // the modal cell
+        assert_eq!(histogram.counts()[[1, 0]], 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bin_edges_panics_for_out_of_bounds_index() {
+        let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+        let histogram = Histogram::new(grid);
+        histogram.bin_edges(&[2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bin_edges_panics_for_dimension_mismatch() {
+        let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]);
+        let histogram = Histogram::new(grid);
+        histogram.bin_edges(&[0, 0]);
+    }
+}
+
+#[cfg(test)]
+mod digitize_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn digitize_increasing_edges() {
+        // Expected values match the output of NumPy's `digitize`
+        let x = array![-1., 0., 0.2, 6.4, 3.0, 1.6, 10., 11.];
+        let edges = array![0., 1., 2.5, 4., 10.];
+        assert_eq!(x.digitize(&edges, false), array![0, 1, 1, 4, 3, 2, 5, 5]);
+        assert_eq!(x.digitize(&edges, true), array![0, 0, 1, 4, 3, 2, 4, 5]);
+    }
+
+    #[test]
+    fn digitize_decreasing_edges() {
+        // Expected values match the output of NumPy's `digitize`
+        let x = array![-1., 0., 0.2, 6.4, 3.0, 1.6, 10., 11.];
+        let edges = array![10., 4., 2.5, 1., 0.];
+        assert_eq!(x.digitize(&edges, false), array![5, 4, 4, 1, 2, 3, 0, 0]);
+        assert_eq!(x.digitize(&edges, true), array![5, 5, 4, 1, 2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn digitize_with_repeated_edges() {
+        let x = array![1, 2, 3];
+        let edges = array![0, 2, 2, 4];
+        assert_eq!(x.digitize(&edges, false), array![1, 3, 3]);
+        assert_eq!(x.digitize(&edges, true), array![1, 1, 3]);
+    }
+
+    #[test]
+    fn digitize_without_edges() {
+        let x = array![1, 2, 3];
+        let edges: Array1<i32> = array![];
+        assert_eq!(x.digitize(&edges, false), array![0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn digitize_panics_for_non_monotonic_edges() {
+        let x = array![1, 2, 3];
+        let edges = array![0, 2, 1, 4];
+        x.digitize(&edges, false);
+    }
+}
+
+#[cfg(test)]
+mod class_histogram_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn class_histogram_counts_every_class() {
+        let labels: Array1<usize> = array![3, 0, 1, 3, 3, 0];
+        assert_eq!(labels.class_histogram(5).unwrap(), array![2, 1, 0, 3, 0]);
+    }
+
+    #[test]
+    fn class_histogram_of_empty_array() {
+        let labels: Array1<u8> = array![];
+        assert_eq!(labels.class_histogram(3).unwrap(), array![0, 0, 0]);
+    }
+
+    #[test]
+    fn class_histogram_fails_for_out_of_range_labels() {
+        let labels: Array1<usize> = array![0, 1, 2];
+        assert!(labels.class_histogram(2).is_err());
+        assert!(labels.class_histogram(0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod confusion_matrix_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn confusion_matrix_counts_label_pairs() {
+        let y_true: Array1<usize> = array![0, 0, 1, 1, 1, 2, 2, 0];
+        let y_pred: Array1<usize> = array![0, 1, 1, 1, 2, 2, 0, 0];
+        let matrix = y_true.confusion_matrix(&y_pred, 3).unwrap();
+        assert_eq!(matrix[[0, 0]], 2);
+        assert_eq!(matrix[[0, 1]], 1);
+        assert_eq!(matrix[[1, 1]], 2);
+        assert_eq!(matrix[[1, 2]], 1);
+        assert_eq!(matrix[[2, 0]], 1);
+        assert_eq!(matrix[[2, 2]], 1);
+        assert_eq!(matrix.sum(), y_true.len());
+        // the row sums are the class histogram of the true labels
+        assert_eq!(matrix.sum_axis(Axis(1)), y_true.class_histogram(3).unwrap());
+    }
+
+    #[test]
+    fn confusion_matrix_fails_for_out_of_range_labels() {
+        let y_true: Array1<usize> = array![0, 1];
+        let y_pred: Array1<usize> = array![0, 2];
+        assert_eq!(
+            y_true.confusion_matrix(&y_pred, 2),
+            Err(ConfusionMatrixError::BinNotFound(BinNotFound))
+        );
+    }
+
+    #[test]
+    fn confusion_matrix_fails_for_length_mismatch() {
+        let y_true: Array1<usize> = array![0, 1, 1];
+        let y_pred: Array1<usize> = array![0, 1];
+        assert_eq!(
+            y_true.confusion_matrix(&y_pred, 2),
+            Err(ConfusionMatrixError::MultiInput(MultiInputError::ShapeMismatch {
+                first: vec![3],
+                second: vec![2],
+            }))
+        );
+    }
+}
+
+#[cfg(test)]
+mod histogram_uniform_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn histogram_uniform_of_evenly_spaced_values() {
+        let a = Array1::range(0., 10., 1.);
+        assert_eq!(a.histogram_uniform(5), array![2, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn histogram_uniform_includes_the_maximum_in_the_last_bin() {
+        let a = array![0., 0.5, 1.];
+        assert_eq!(a.histogram_uniform(2), array![1, 2]);
+    }
+
+    #[test]
+    fn histogram_uniform_of_constant_array() {
+        let a = array![3., 3., 3.];
+        assert_eq!(a.histogram_uniform(3), array![0, 3, 0]);
+    }
+
+    #[test]
+    fn histogram_uniform_ignores_nan() {
+        let a = array![f64::NAN, 1., 2., f64::NAN, 3.];
+        assert_eq!(a.histogram_uniform(2), array![1, 2]);
+    }
+
+    #[test]
+    fn histogram_uniform_of_empty_array() {
+        let a: Array1<f64> = array![];
+        assert_eq!(a.histogram_uniform(4), array![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn histogram_uniform_with_extremes_close_to_the_largest_float() {
+        let a = array![-f64::MAX, f64::MAX, 0.];
+        assert_eq!(a.histogram_uniform(4), array![1, 0, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_uniform_panics_without_bins() {
+        array![1., 2.].histogram_uniform(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_uniform_panics_for_infinite_values() {
+        array![1., f64::INFINITY].histogram_uniform(2);
+    }
+}
+
+#[cfg(test)]
+mod chi_square_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn chi_square_of_identical_frequencies_is_zero() {
+        let observed = array![3., 0., 7., 12.];
+        assert_eq!(observed.chi_square(&observed), Ok(0.));
+    }
+
+    #[test]
+    fn chi_square_of_a_fair_die() {
+        let observed = array![5., 8., 9., 8., 10., 20.];
+        let expected = Array1::from_elem(6, 10.);
+        assert!((observed.chi_square(&expected).unwrap() - 13.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn chi_square_with_zero_expected_frequencies() {
+        let expected = array![0., 5., 5.];
+        assert_eq!(array![0., 4., 6.].chi_square(&expected), Ok(0.4));
+        assert_eq!(array![1., 4., 5.].chi_square(&expected), Ok(f64::INFINITY));
+    }
+
+    #[test]
+    fn chi_square_propagates_nan_before_a_zero_expected_frequency() {
+        let expected = array![5., 0.];
+        assert!(array![f64::NAN, 1.].chi_square(&expected).unwrap().is_nan());
+    }
+
+    #[test]
+    fn chi_square_errors() {
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.chi_square(&empty), Err(MultiInputError::EmptyInput));
+        assert_eq!(
+            array![1., 2.].chi_square(&array![1., 2., 3.]),
+            Err(MultiInputError::ShapeMismatch {
+                first: vec![2],
+                second: vec![3],
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod histogram_axis_tests {
+    use super::*;
+    use super::super::Edges;
+    use ndarray::{array, s};
+
+    #[test]
+    fn histogram_of_each_column() {
+        let bins = Bins::new(Edges::from(vec![0, 10, 20, 30]));
+        let a = array![
+            [1, 15, 29],
+            [12, 3, 28],
+            [25, 19, 40],
+            [11, -5, 20],
+        ];
+        let histograms = a.histogram_axis(Axis(0), &bins);
+        assert_eq!(histograms.dim(), (3, 3));
+        // Each column of `a` becomes a column of counts
+        assert_eq!(histograms.column(0), array![1, 2, 1]);
+        // -5 and 40 are outside of the bins
+        assert_eq!(histograms.column(1), array![1, 2, 0]);
+        assert_eq!(histograms.column(2), array![0, 0, 3]);
+    }
+
+    #[test]
+    fn histogram_axis_of_3d_array() {
+        let bins = Bins::new(Edges::from(vec![0, 2, 4]));
+        let a = Array::from_shape_vec((2, 4, 3), (0..24).map(|x| x % 5).collect()).unwrap();
+        let histograms = a.histogram_axis(Axis(1), &bins);
+        assert_eq!(histograms.shape(), &[2, 2, 3]);
+        for i in 0..2 {
+            for k in 0..3 {
+                let lane = a.slice(s![i, .., k]);
+                let expected = array![
+                    lane.iter().filter(|&&x| x < 2).count(),
+                    lane.iter().filter(|&&x| (2..4).contains(&x)).count()
+                ];
+                assert_eq!(histograms.slice(s![i, .., k]), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bincount_of_each_column() {
+        let labels: Array2<usize> = array![
+            [0, 3, 1],
+            [2, 3, 1],
+            [0, 0, 1],
+            [1, 3, 2],
+            [0, 2, 1],
+        ];
+        let counts = labels.bincount_axis(Axis(0), 4).unwrap();
+        assert_eq!(counts.dim(), (4, 3));
+        assert_eq!(counts.column(1), array![1, 0, 1, 3]);
+        for (j, column) in labels.gencolumns().into_iter().enumerate() {
+            assert_eq!(counts.column(j), column.class_histogram(4).unwrap());
+        }
+        // Counting along rows instead
+        let counts = labels.bincount_axis(Axis(1), 4).unwrap();
+        assert_eq!(counts.dim(), (5, 4));
+        assert_eq!(counts.row(3), array![0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn bincount_axis_with_labels_out_of_range() {
+        let labels: Array2<u8> = array![[0, 1], [3, 1]];
+        assert!(labels.bincount_axis(Axis(0), 3).is_err());
+        assert!(labels.bincount_axis(Axis(0), 4).is_ok());
+    }
+
+    #[test]
+    fn histogram_axis_with_empty_lanes() {
+        let bins = Bins::new(Edges::from(vec![0, 1]));
+        let a = Array2::<i32>::zeros((0, 3));
+        assert_eq!(a.histogram_axis(Axis(0), &bins), Array2::<usize>::zeros((1, 3)));
+    }
 }
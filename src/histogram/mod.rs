@@ -1,7 +1,8 @@
 //! Histogram functionalities.
-pub use self::histograms::{Histogram, HistogramExt};
+pub use self::histograms::{Histogram, HistogramExt, Histogram1dExt, HistogramAxisExt};
 pub use self::bins::{Edges, Bins};
 pub use self::grid::{Grid, GridBuilder};
+pub(crate) use self::histograms::class_counts;
 
 mod histograms;
 mod bins;
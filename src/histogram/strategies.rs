@@ -10,6 +10,10 @@
 //!
 //! `bin_width = (max - min)/n`
 //!
+//! For integer types the division above is integer division, hence `bin_width`
+//! is rounded down (`max - min` is never negative): e.g. `min = 0`, `max = 10`
+//! and `n = 3` result in `bin_width = 3`.
+//!
 //! All our bins are left-inclusive and right-exclusive: we make sure to add an extra bin
 //! if it is necessary to include the maximum value of the array that has been passed as argument
 //! to the `from_array` method.
@@ -402,6 +406,8 @@ impl<T> Auto<T>
 ///
 /// `bin_width = (max - min)/n`
 ///
+/// For integer types it is rounded down (floor), as with integer division.
+///
 /// **Panics** if `n_bins == 0` and division by 0 panics for `T`.
 fn compute_bin_width<T>(min: T, max: T, n_bins: usize) -> T
 where
@@ -434,6 +440,18 @@ mod sqrt_tests {
         Sqrt::from_array(&array![1, 1, 1, 1, 1, 1, 1]);
     }
 
+    #[test]
+    fn integer_bin_width_is_rounded_down() {
+        // 11 observations: round(sqrt(11)) = 3 bins of width floor(10 / 3) = 3,
+        // plus an extra bin to include the maximum
+        let strategy = Sqrt::from_array(&Array1::from_iter(0..=10));
+        assert_eq!(strategy.bin_width(), 3);
+        assert_eq!(strategy.n_bins(), 4);
+        let bins = strategy.build();
+        assert_eq!(bins.index_of(&10), Some(3));
+        assert_eq!(bins.index(3), 9..12);
+    }
+
     #[should_panic]
     #[test]
     fn empty_arrays_cause_panic() {
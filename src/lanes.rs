@@ -0,0 +1,61 @@
+use ndarray::iter::LanesIter;
+use ndarray::prelude::*;
+use ndarray::Data;
+
+/// Returns an iterator over the 1-dimensional lanes of `array` along `axis`,
+/// i.e. the 1-dimensional views obtained fixing all indices but the one
+/// along `axis`, in logical order.
+///
+/// For a 2-dimensional array, the lanes along `Axis(0)` are its columns
+/// while the lanes along `Axis(1)` are its rows.
+///
+/// This is a shorthand for `array.lanes(axis).into_iter()`, the traversal
+/// shared by the methods of this crate computing a statistic for each lane.
+///
+/// **Panics** if `axis` is out of bounds.
+///
+/// # Example
+///
+/// ```
+/// extern crate ndarray;
+/// extern crate ndarray_stats;
+/// use ndarray::{array, Axis};
+/// use ndarray_stats::axis_lanes;
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// let column_sums: Vec<i32> = axis_lanes(&a, Axis(0)).map(|column| column.sum()).collect();
+/// assert_eq!(column_sums, vec![5, 7, 9]);
+/// ```
+pub fn axis_lanes<A, S, D>(array: &ArrayBase<S, D>, axis: Axis) -> LanesIter<'_, A, D::Smaller>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    array.lanes(axis).into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_axis_lanes_of_columns_and_rows() {
+        let a = array![[1., 2.], [3., 4.], [5., 6.]];
+        let column_sums: Vec<f64> = axis_lanes(&a, Axis(0)).map(|c| c.sum()).collect();
+        assert_eq!(column_sums, vec![9., 12.]);
+        let row_sums: Vec<f64> = axis_lanes(&a, Axis(1)).map(|r| r.sum()).collect();
+        assert_eq!(row_sums, vec![3., 7., 11.]);
+    }
+
+    #[test]
+    fn test_axis_lanes_3d() {
+        let a = Array::from_shape_vec((2, 3, 2), (0..12).collect()).unwrap();
+        let lanes: Vec<Vec<i32>> = axis_lanes(&a, Axis(1)).map(|l| l.to_vec()).collect();
+        assert_eq!(
+            lanes,
+            vec![vec![0, 2, 4], vec![1, 3, 5], vec![6, 8, 10], vec![7, 9, 11]]
+        );
+        assert_eq!(axis_lanes(&a, Axis(1)).len(), 4);
+    }
+}
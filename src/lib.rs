@@ -4,8 +4,13 @@
 //! Currently available routines include:
 //! - [`order statistics`] (minimum, maximum, quantiles, etc.);
 //! - [`partitioning`];
-//! - [`correlation analysis`] (covariance, pearson correlation);
-//! - [`histogram computation`].
+//! - [`ranking`];
+//! - [`correlation analysis`] (covariance, pearson correlation, kendall tau);
+//! - [`histogram computation`];
+//! - [`distance computation`] (euclidean, manhattan, chebyshev, custom metrics);
+//! - [`information theory`] (entropy, softmax, log-sum-exp);
+//! - [`array transformations`] (clipping, NaN replacement, exponentially weighted moving average,
+//!   downsampling, histogram equalization, normalization).
 //!
 //! Please feel free to contribute new functionality! A roadmap can be found [`here`].
 //!
@@ -17,8 +22,12 @@
 //! [`ndarray`]: https://github.com/rust-ndarray/ndarray
 //! [`order statistics`]: trait.QuantileExt.html
 //! [`partitioning`]: trait.Sort1dExt.html
+//! [`ranking`]: trait.RankExt.html
 //! [`correlation analysis`]: trait.CorrelationExt.html
 //! [`histogram computation`]: histogram/index.html
+//! [`distance computation`]: trait.DistanceExt.html
+//! [`information theory`]: trait.EntropyExt.html
+//! [`array transformations`]: trait.TransformExt.html
 //! [`here`]: https://github.com/jturner314/ndarray-stats/issues/1
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy-1.14.1/reference/routines.statistics.html
 //! [`StatsBase.jl`]: https://juliastats.github.io/StatsBase.jl/latest/
@@ -37,16 +46,27 @@ extern crate quickcheck;
 #[cfg(test)]
 extern crate approx;
 
+pub use entropy::EntropyExt;
+pub use lanes::axis_lanes;
 pub use maybe_nan::{MaybeNan, MaybeNanExt};
-pub use quantile::{interpolate, QuantileExt, Quantile1dExt};
-pub use sort::Sort1dExt;
-pub use correlation::CorrelationExt;
-pub use histogram::HistogramExt;
+pub use quantile::{interpolate, median_of_two_sorted, DescribeResult, QuantileExt, Quantile1dExt, Quantile2dExt, Summary};
+pub use rank::{RankExt, TieMethod};
+pub use sort::{PartitionStats, Sort1dExt, SortedView};
+pub use correlation::{CorrelationExt, Correlation1dExt};
+pub use distance::DistanceExt;
+pub use histogram::{HistogramExt, Histogram1dExt, HistogramAxisExt};
 pub use summary_statistics::SummaryStatisticsExt;
+pub use transform::{Norm, TransformExt, Transform1dExt, Transform2dExt};
 
+mod entropy;
+mod lanes;
 mod maybe_nan;
 mod quantile;
+mod rank;
 mod sort;
 mod correlation;
 mod summary_statistics;
+mod transform;
 pub mod histogram;
+pub mod distance;
+pub mod errors;
@@ -1,7 +1,10 @@
-use interpolate::Interpolate;
+use interpolate::{Interpolate, Linear, OrdinalInterpolate};
 use ndarray::prelude::*;
-use ndarray::{s, Data, DataMut, RemoveAxis};
+use ndarray::{s, Data, DataMut, RemoveAxis, Zip};
+use num_traits::{Float, FromPrimitive, ToPrimitive};
+use errors::{EmptyInput, MultiInputError};
 use std::cmp;
+use std::collections::BinaryHeap;
 use {MaybeNan, MaybeNanExt, Sort1dExt};
 
 /// Interpolation strategies.
@@ -43,8 +46,29 @@ pub mod interpolate {
         ) -> Array<T, D>
         where
             D: Dimension;
+        #[doc(hidden)]
+        fn interpolate_elem(lower: Option<T>, higher: Option<T>, q: f64, len: usize) -> T {
+            Self::interpolate(lower.map(arr0), higher.map(arr0), q, len)
+                .into_raw_vec()
+                .remove(0)
+        }
     }
 
+    /// Marker trait for the interpolation strategies that always return
+    /// one of the data points — [`Lower`], [`Higher`] and [`Nearest`] —
+    /// and therefore do not require any arithmetic on the elements.
+    ///
+    /// Used by [`quantile_ordinal_mut`] to reject, at compile time,
+    /// strategies that compute new values (e.g. [`Linear`], [`Midpoint`]).
+    ///
+    /// [`Lower`]: struct.Lower.html
+    /// [`Higher`]: struct.Higher.html
+    /// [`Nearest`]: struct.Nearest.html
+    /// [`Linear`]: struct.Linear.html
+    /// [`Midpoint`]: struct.Midpoint.html
+    /// [`quantile_ordinal_mut`]: ../trait.Quantile1dExt.html#tymethod.quantile_ordinal_mut
+    pub trait OrdinalInterpolate<T>: Interpolate<T> {}
+
     /// Select the higher value.
     pub struct Higher;
     /// Select the lower value.
@@ -73,6 +97,9 @@ pub mod interpolate {
         ) -> Array<T, D> {
             higher.unwrap()
         }
+        fn interpolate_elem(_lower: Option<T>, higher: Option<T>, _q: f64, _len: usize) -> T {
+            higher.unwrap()
+        }
     }
 
     impl<T> Interpolate<T> for Lower {
@@ -90,6 +117,9 @@ pub mod interpolate {
         ) -> Array<T, D> {
             lower.unwrap()
         }
+        fn interpolate_elem(lower: Option<T>, _higher: Option<T>, _q: f64, _len: usize) -> T {
+            lower.unwrap()
+        }
     }
 
     impl<T> Interpolate<T> for Nearest {
@@ -111,8 +141,19 @@ pub mod interpolate {
                 higher.unwrap()
             }
         }
+        fn interpolate_elem(lower: Option<T>, higher: Option<T>, q: f64, len: usize) -> T {
+            if <Self as Interpolate<T>>::needs_lower(q, len) {
+                lower.unwrap()
+            } else {
+                higher.unwrap()
+            }
+        }
     }
 
+    impl<T> OrdinalInterpolate<T> for Higher {}
+    impl<T> OrdinalInterpolate<T> for Lower {}
+    impl<T> OrdinalInterpolate<T> for Nearest {}
+
     impl<T> Interpolate<T> for Midpoint
     where
         T: NumOps + Clone + FromPrimitive,
@@ -126,22 +167,29 @@ pub mod interpolate {
         fn interpolate<D>(
             lower: Option<Array<T, D>>,
             higher: Option<Array<T, D>>,
-            _q: f64,
-            _len: usize,
+            q: f64,
+            len: usize,
         ) -> Array<T, D>
         where
             D: Dimension,
         {
-            let denom = T::from_u8(2).unwrap();
             let mut lower = lower.unwrap();
             let higher = higher.unwrap();
             azip!(
                 mut lower, ref higher in {
-                    *lower = lower.clone() + (higher.clone() - lower.clone()) / denom.clone()
+                    *lower = <Self as Interpolate<T>>::interpolate_elem(
+                        Some(lower.clone()), Some(higher.clone()), q, len
+                    )
                 }
             );
             lower
         }
+        fn interpolate_elem(lower: Option<T>, higher: Option<T>, _q: f64, _len: usize) -> T {
+            let denom = T::from_u8(2).unwrap();
+            let lower = lower.unwrap();
+            let higher = higher.unwrap();
+            lower.clone() + (higher - lower) / denom
+        }
     }
 
     impl<T> Interpolate<T> for Linear
@@ -163,19 +211,80 @@ pub mod interpolate {
         where
             D: Dimension,
         {
-            let fraction = <Self as Interpolate<T>>::float_quantile_index_fraction(q, len);
             let mut a = lower.unwrap();
             let b = higher.unwrap();
             azip!(mut a, ref b in {
-                let a_f64 = a.to_f64().unwrap();
-                let b_f64 = b.to_f64().unwrap();
-                *a = a.clone() + T::from_f64((b_f64 - a_f64) * fraction).unwrap();
+                *a = <Self as Interpolate<T>>::interpolate_elem(
+                    Some(a.clone()), Some(b.clone()), q, len
+                )
             });
             a
         }
+        fn interpolate_elem(lower: Option<T>, higher: Option<T>, q: f64, len: usize) -> T {
+            let fraction = <Self as Interpolate<T>>::float_quantile_index_fraction(q, len);
+            let a = lower.unwrap();
+            let b = higher.unwrap();
+            let a_f64 = a.to_f64().unwrap();
+            let b_f64 = b.to_f64().unwrap();
+            a + T::from_f64((b_f64 - a_f64) * fraction).unwrap()
+        }
     }
 }
 
+/// Descriptive statistics for each 1-dimensional lane of an array,
+/// as returned by [`describe_axis_mut`].
+///
+/// Each array field holds one value per lane, taken from the [`Summary`]
+/// of that lane.
+///
+/// [`describe_axis_mut`]: trait.QuantileExt.html#tymethod.describe_axis_mut
+/// [`Summary`]: struct.Summary.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescribeResult<A, D: Dimension> {
+    /// Number of elements in each lane.
+    pub count: usize,
+    /// Arithmetic mean of each lane.
+    pub mean: Array<A, D>,
+    /// Sample standard deviation (`ddof = 1`) of each lane,
+    /// `None` if the lanes have a single element.
+    pub std: Option<Array<A, D>>,
+    /// Minimum of each lane.
+    pub min: Array<A, D>,
+    /// First quartile (`q=0.25`) of each lane.
+    pub first_quartile: Array<A, D>,
+    /// Median (`q=0.5`) of each lane.
+    pub median: Array<A, D>,
+    /// Third quartile (`q=0.75`) of each lane.
+    pub third_quartile: Array<A, D>,
+    /// Maximum of each lane.
+    pub max: Array<A, D>,
+}
+
+/// Descriptive statistics of a 1-dimensional array,
+/// as returned by [`describe_mut`].
+///
+/// [`describe_mut`]: trait.Quantile1dExt.html#tymethod.describe_mut
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary<A> {
+    /// Number of elements.
+    pub count: usize,
+    /// Arithmetic mean.
+    pub mean: A,
+    /// Sample standard deviation (`ddof = 1`),
+    /// `None` if there is a single element.
+    pub std: Option<A>,
+    /// Minimum.
+    pub min: A,
+    /// First quartile (`q=0.25`).
+    pub first_quartile: A,
+    /// Median (`q=0.5`).
+    pub median: A,
+    /// Third quartile (`q=0.75`).
+    pub third_quartile: A,
+    /// Maximum.
+    pub max: A,
+}
+
 /// Quantile methods for `ArrayBase`.
 pub trait QuantileExt<A, S, D>
 where
@@ -224,6 +333,29 @@ where
         A: MaybeNan,
         A::NotNan: Ord;
 
+    /// Returns the range (maximum minus minimum) of the finite elements of
+    /// the array, skipping NaN and infinite values.
+    ///
+    /// Both extremes are found in a single pass over the array.
+    ///
+    /// Returns `None` if the array does not contain any finite element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::QuantileExt;
+    /// use std::f64;
+    ///
+    /// let a = array![[2., f64::NAN], [-1.5, 4.]];
+    /// assert_eq!(a.peak_to_peak_skipnan(), Some(5.5));
+    /// ```
+    fn peak_to_peak_skipnan(&self) -> Option<A>
+    where
+        A: Float;
+
     /// Return the qth quantile of the data along the specified axis.
     ///
     /// `q` needs to be a float between 0 and 1, bounds included.
@@ -252,6 +384,9 @@ where
     /// - worst case: O(`m`^2);
     /// where `m` is the number of elements in the array.
     ///
+    /// For `q=0.` and `q=1.` the minimum (or the maximum) of each lane is found
+    /// with a single linear scan instead, leaving the array untouched.
+    ///
     /// **Panics** if `axis` is out of bounds, if the axis has length 0, or if
     /// `q` is not between `0.` and `1.` (inclusive).
     fn quantile_axis_mut<I>(&mut self, axis: Axis, q: f64) -> Array<A, D::Smaller>
@@ -261,6 +396,50 @@ where
         S: DataMut,
         I: Interpolate<A>;
 
+    /// Write the `q`th quantile of the data along the specified axis into `out`,
+    /// instead of allocating a new array.
+    ///
+    /// This is useful when quantiles are computed repeatedly (e.g. in a loop),
+    /// since the same output buffer can be reused across calls: each lane is
+    /// selected in place and its quantile is written directly into `out`, so
+    /// no heap allocation proportional to the number of lanes takes place.
+    /// See [`quantile_axis_mut`](#tymethod.quantile_axis_mut) for the definition
+    /// of quantiles; the array is shuffled **in place** along each 1-dimensional lane.
+    ///
+    /// Returns `Err(MultiInputError::ShapeMismatch)` if the shape of `out` is not
+    /// the shape of the array with `axis` removed, and
+    /// `Err(MultiInputError::EmptyInput)` if the axis has length 0.
+    /// `out` is left untouched in both cases.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `q` is not between `0.`
+    /// and `1.` (inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Array1, Axis};
+    /// use ndarray_stats::{interpolate::Lower, QuantileExt};
+    ///
+    /// let mut out = Array1::zeros(3);
+    /// let mut a = array![[4, 1, 6], [2, 5, 3], [0, 8, 7]];
+    /// a.quantile_axis_into_mut::<Lower, _>(Axis(0), 0.5, &mut out).unwrap();
+    /// assert_eq!(out, array![2, 5, 6]);
+    /// ```
+    fn quantile_axis_into_mut<I, S2>(
+        &mut self,
+        axis: Axis,
+        q: f64,
+        out: &mut ArrayBase<S2, D::Smaller>,
+    ) -> Result<(), MultiInputError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone,
+        S: DataMut,
+        S2: DataMut<Elem = A>,
+        I: Interpolate<A>;
+
     /// Return the `q`th quantile of the data along the specified axis, skipping NaN values.
     ///
     /// See [`quantile_axis_mut`](##tymethod.quantile_axis_mut) for details.
@@ -271,6 +450,39 @@ where
         A::NotNan: Clone + Ord,
         S: DataMut,
         I: Interpolate<A::NotNan>;
+
+    /// Return count, mean, standard deviation, minimum, quartiles and maximum
+    /// of the data along the specified axis, in the spirit of pandas'
+    /// `DataFrame.describe()`.
+    ///
+    /// The standard deviation is the sample standard deviation (`ddof = 1`),
+    /// while the quartiles are computed using [`Linear`] interpolation.
+    /// See [`quantile_axis_mut`](#tymethod.quantile_axis_mut) for the definition
+    /// of quantiles.
+    /// All the statistics of a lane are computed in one go: its minimum, quartiles
+    /// and maximum are selected together, with
+    /// [`quantiles_mut`](trait.Quantile1dExt.html#tymethod.quantiles_mut).
+    ///
+    /// The array is shuffled **in place** along each 1-dimensional lane, as
+    /// in [`quantile_axis_mut`](#tymethod.quantile_axis_mut).
+    ///
+    /// The elements must be both `Float` and `Ord`: plain `f32`/`f64` do not
+    /// qualify, use the noisy float types (e.g. `N64` or `R64`) instead,
+    /// converting the array with `mapv(n64)` if needed.
+    ///
+    /// If the axis has length 1 the sample standard deviation is not defined
+    /// and `std` is `None`, as in
+    /// [`describe_mut`](trait.Quantile1dExt.html#tymethod.describe_mut).
+    ///
+    /// **Panics** if `axis` is out of bounds, if the axis has length 0
+    /// or if the type cast of the axis length from `usize` to `A` fails.
+    ///
+    /// [`Linear`]: interpolate/struct.Linear.html
+    fn describe_axis_mut(&mut self, axis: Axis) -> DescribeResult<A, D::Smaller>
+    where
+        D: RemoveAxis,
+        A: Float + FromPrimitive + Ord,
+        S: DataMut;
 }
 
 impl<A, S, D> QuantileExt<A, S, D> for ArrayBase<S, D>
@@ -314,6 +526,22 @@ where
         })
     }
 
+    fn peak_to_peak_skipnan(&self) -> Option<A>
+    where
+        A: Float,
+    {
+        let extremes = self.fold(None, |acc, &elem| {
+            if !elem.is_finite() {
+                return acc;
+            }
+            Some(match acc {
+                Some((min, max)) => (elem.min(min), elem.max(max)),
+                None => (elem, elem),
+            })
+        });
+        extremes.map(|(min, max)| max - min)
+    }
+
     fn max_skipnan(&self) -> &A
     where
         A: MaybeNan,
@@ -335,29 +563,58 @@ where
         S: DataMut,
         I: Interpolate<A>,
     {
-        assert!((0. <= q) && (q <= 1.));
-        let mut lower = None;
-        let mut higher = None;
+        assert!((0. ..=1.).contains(&q));
         let axis_len = self.len_of(axis);
-        if I::needs_lower(q, axis_len) {
-            let lower_index = I::lower_index(q, axis_len);
-            lower = Some(self.map_axis_mut(axis, |mut x| x.sorted_get_mut(lower_index)));
-            if I::needs_higher(q, axis_len) {
-                let higher_index = I::higher_index(q, axis_len);
-                let relative_higher_index = higher_index - lower_index;
-                higher = Some(self.map_axis_mut(axis, |mut x| {
-                    x.slice_mut(s![lower_index..])
-                        .sorted_get_mut(relative_higher_index)
-                }));
-            };
-        } else {
-            higher = Some(
-                self.map_axis_mut(axis, |mut x| x.sorted_get_mut(I::higher_index(q, axis_len))),
-            );
-        };
+        let dim = self.raw_dim().remove_axis(axis);
+        let needs_lower = I::needs_lower(q, axis_len);
+        let needs_higher = I::needs_higher(q, axis_len);
+        let mut lower = Vec::with_capacity(if needs_lower { dim.size() } else { 0 });
+        let mut higher = Vec::with_capacity(if needs_higher { dim.size() } else { 0 });
+        for mut lane in self.lanes_mut(axis) {
+            let (lane_lower, lane_higher) = select_quantile_points_mut::<A, I>(&mut lane, q);
+            lower.extend(lane_lower);
+            higher.extend(lane_higher);
+        }
+        let to_array = |points| Array::from_shape_vec(dim.clone(), points).unwrap();
+        let lower = if needs_lower { Some(to_array(lower)) } else { None };
+        let higher = if needs_higher { Some(to_array(higher)) } else { None };
         I::interpolate(lower, higher, q, axis_len)
     }
 
+    fn quantile_axis_into_mut<I, S2>(
+        &mut self,
+        axis: Axis,
+        q: f64,
+        out: &mut ArrayBase<S2, D::Smaller>,
+    ) -> Result<(), MultiInputError>
+    where
+        D: RemoveAxis,
+        A: Ord + Clone,
+        S: DataMut,
+        S2: DataMut<Elem = A>,
+        I: Interpolate<A>,
+    {
+        assert!((0. ..=1.).contains(&q));
+        let expected_dim = self.raw_dim().remove_axis(axis);
+        if out.raw_dim() != expected_dim {
+            return Err(MultiInputError::ShapeMismatch {
+                first: expected_dim.slice().to_vec(),
+                second: out.shape().to_vec(),
+            });
+        }
+        let axis_len = self.len_of(axis);
+        if axis_len == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        Zip::from(out)
+            .and(self.lanes_mut(axis))
+            .apply(|quantile, mut lane| {
+                let (lower, higher) = select_quantile_points_mut::<A, I>(&mut lane, q);
+                *quantile = I::interpolate_elem(lower, higher, q, axis_len);
+            });
+        Ok(())
+    }
+
     fn quantile_axis_skipnan_mut<I>(&mut self, axis: Axis, q: f64) -> Array<A, D::Smaller>
     where
         D: RemoveAxis,
@@ -380,6 +637,104 @@ where
             })
         })
     }
+
+    fn describe_axis_mut(&mut self, axis: Axis) -> DescribeResult<A, D::Smaller>
+    where
+        D: RemoveAxis,
+        A: Float + FromPrimitive + Ord,
+        S: DataMut,
+    {
+        let count = self.len_of(axis);
+        assert!(count > 0, "The length of `axis` has to be strictly positive.");
+        let dim = self.raw_dim().remove_axis(axis);
+        let summaries: Vec<Summary<A>> = self
+            .lanes_mut(axis)
+            .into_iter()
+            .map(describe_lane_mut)
+            .collect();
+        let field = |f: fn(&Summary<A>) -> A| {
+            Array::from_shape_vec(dim.clone(), summaries.iter().map(f).collect()).unwrap()
+        };
+        DescribeResult {
+            count,
+            mean: field(|s| s.mean),
+            std: if count >= 2 { Some(field(|s| s.std.unwrap())) } else { None },
+            min: field(|s| s.min),
+            first_quartile: field(|s| s.first_quartile),
+            median: field(|s| s.median),
+            third_quartile: field(|s| s.third_quartile),
+            max: field(|s| s.max),
+        }
+    }
+}
+
+/// Computes the descriptive statistics of a non-empty lane, shuffling it **in place**:
+/// minimum, quartiles and maximum are selected together, with `quantiles_mut`.
+fn describe_lane_mut<A>(mut lane: ArrayViewMut1<A>) -> Summary<A>
+where
+    A: Float + FromPrimitive + Ord,
+{
+    let count = lane.len();
+    let n = A::from_usize(count).unwrap();
+    let mean = lane.fold(A::zero(), |sum, &x| sum + x) / n;
+    let std = if count >= 2 {
+        let squared_deviations = lane.fold(A::zero(), |sum, &x| sum + (x - mean) * (x - mean));
+        Some((squared_deviations / (n - A::one())).sqrt())
+    } else {
+        None
+    };
+    let quantiles = lane
+        .quantiles_mut::<Linear>(&[0., 0.25, 0.5, 0.75, 1.])
+        .unwrap();
+    Summary {
+        count,
+        mean,
+        std,
+        min: quantiles[0],
+        first_quartile: quantiles[1],
+        median: quantiles[2],
+        third_quartile: quantiles[3],
+        max: quantiles[4],
+    }
+}
+
+/// Selects **in place** the data points of `lane` surrounding its `q`th quantile,
+/// returning the lower and the higher one if they are needed by `I`.
+///
+/// For `q=0.` and `q=1.` the minimum (or the maximum) is found with a linear scan.
+fn select_quantile_points_mut<A, I>(lane: &mut ArrayViewMut1<A>, q: f64) -> (Option<A>, Option<A>)
+where
+    A: Ord + Clone,
+    I: Interpolate<A>,
+{
+    let len = lane.len();
+    let needs_lower = I::needs_lower(q, len);
+    let needs_higher = I::needs_higher(q, len);
+    if q == 0. || q == 1. {
+        // Lower and higher index coincide: a single linear scan for the
+        // minimum (or the maximum) is enough.
+        let extreme = if q == 0. { lane.iter().min() } else { lane.iter().max() };
+        let extreme = extreme.unwrap().clone();
+        return match (needs_lower, needs_higher) {
+            (true, true) => (Some(extreme.clone()), Some(extreme)),
+            (true, false) => (Some(extreme), None),
+            (false, _) => (None, Some(extreme)),
+        };
+    }
+    if needs_lower {
+        let lower_index = I::lower_index(q, len);
+        let lower = lane.sorted_get_mut(lower_index);
+        let higher = if needs_higher {
+            // Elements after `lower_index` are all greater than or equal to `lower`
+            let relative_higher_index = I::higher_index(q, len) - lower_index;
+            Some(lane.slice_mut(s![lower_index..]).sorted_get_mut(relative_higher_index))
+        } else {
+            None
+        };
+        (Some(lower), higher)
+    } else {
+        (None, Some(lane.sorted_get_mut(I::higher_index(q, len))))
+    }
 }
 
 /// Quantile methods for 1-D arrays.
@@ -416,11 +771,366 @@ pub trait Quantile1dExt<A, S>
     /// Returns `None` if the array is empty.
     ///
     /// **Panics** if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// If the elements of the array do not support arithmetic
+    /// (or interpolating between them makes no sense, e.g. for integers)
+    /// use [`quantile_ordinal_mut`](#tymethod.quantile_ordinal_mut).
     fn quantile_mut<I>(&mut self, q: f64) -> Option<A>
     where
         A: Ord + Clone,
         S: DataMut,
         I: Interpolate<A>;
+
+    /// Return the qth quantile of the data, using an interpolation strategy
+    /// that always selects one of the data points ([`Lower`], [`Higher`] or [`Nearest`]).
+    ///
+    /// It only requires the elements to be `Ord`: using a strategy
+    /// that computes new values (e.g. [`Linear`]) is a compile-time error.
+    ///
+    /// See [`quantile_mut`](#tymethod.quantile_mut) for details.
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Nearest, Quantile1dExt};
+    ///
+    /// let mut a = array![4, 1, 3, 2];
+    /// assert_eq!(a.quantile_ordinal_mut::<Nearest>(0.7), Some(3));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Linear, Quantile1dExt};
+    ///
+    /// let mut a = array![4, 1, 3, 2];
+    /// a.quantile_ordinal_mut::<Linear>(0.7);
+    /// ```
+    ///
+    /// [`Lower`]: interpolate/struct.Lower.html
+    /// [`Higher`]: interpolate/struct.Higher.html
+    /// [`Nearest`]: interpolate/struct.Nearest.html
+    /// [`Linear`]: interpolate/struct.Linear.html
+    fn quantile_ordinal_mut<I>(&mut self, q: f64) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: OrdinalInterpolate<A>;
+
+    /// Return the quantiles of the data for each `q` in `qs`, in the
+    /// same order as `qs`.
+    ///
+    /// The required elements are selected in a single left-to-right pass,
+    /// each selection operating only on the elements that follow the
+    /// previously selected one: this is cheaper than calling
+    /// [`quantile_mut`](#tymethod.quantile_mut) once for each `q`.
+    ///
+    /// See [`quantile_mut`](#tymethod.quantile_mut) for details.
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// **Panics** if any `q` in `qs` is not between `0.` and `1.` (inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Lower, Quantile1dExt};
+    ///
+    /// let mut a = array![10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+    /// assert_eq!(a.quantiles_mut::<Lower>(&[0.9, 0., 0.5]), Some(array![9, 1, 5]));
+    /// ```
+    fn quantiles_mut<I>(&mut self, qs: &[f64]) -> Option<Array1<A>>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+
+    /// Return the first quartile of the data, i.e. its quantile for `q=0.25`.
+    ///
+    /// See [`quantile_mut`](#tymethod.quantile_mut) for details.
+    ///
+    /// Returns `None` if the array is empty.
+    fn first_quartile_mut<I>(&mut self) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+
+    /// Return the third quartile of the data, i.e. its quantile for `q=0.75`.
+    ///
+    /// See [`quantile_mut`](#tymethod.quantile_mut) for details.
+    ///
+    /// Returns `None` if the array is empty.
+    fn third_quartile_mut<I>(&mut self) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+
+    /// Return the first quartile, the median and the third quartile of the data
+    /// (i.e. its quantiles for `q=0.25`, `q=0.5` and `q=0.75`), computed
+    /// together with [`quantiles_mut`](#tymethod.quantiles_mut).
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Nearest, Quantile1dExt};
+    ///
+    /// let mut a = array![7, 1, 5, 3, 9];
+    /// assert_eq!(a.quartiles_mut::<Nearest>(), Some((3, 5, 7)));
+    /// ```
+    fn quartiles_mut<I>(&mut self) -> Option<(A, A, A)>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+
+    /// [Winsorize](https://en.wikipedia.org/wiki/Winsorizing) the data
+    /// **in place**: elements smaller than the `lower` quantile are replaced
+    /// by the `lower` quantile, elements greater than the `upper` quantile are
+    /// replaced by the `upper` quantile.
+    ///
+    /// The two quantiles are computed with [`quantiles_mut`](#tymethod.quantiles_mut)
+    /// (using `I` to interpolate) directly on `self`, without allocating a copy
+    /// of the data: the order of the elements of `self` is **not** preserved.
+    /// Use `winsorize_mut` on a copy if the order matters.
+    ///
+    /// Nothing happens if the array is empty.
+    ///
+    /// **Panics** if `lower` or `upper` are not in `[0, 1]` or if `lower >= upper`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Nearest, Quantile1dExt};
+    ///
+    /// let mut a = array![50, 1, 2, 3, 4, 5, 6, 7, 8, -20, 9];
+    /// a.winsorize_mut::<Nearest>(0.1, 0.9);
+    /// let mut values = a.to_vec();
+    /// values.sort();
+    /// assert_eq!(values, vec![1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 9]);
+    /// ```
+    fn winsorize_mut<I>(&mut self, lower: f64, upper: f64)
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+
+    /// Partition the data **in place** so that the elements between the
+    /// `lower` and the `upper` quantile occupy a contiguous range,
+    /// returning a mutable view of them.
+    ///
+    /// With `n` the number of elements, the view contains the elements
+    /// that would be indexed as `ceil((n-1)lower)` to `floor((n-1)upper)`
+    /// (bounds included) if the array were to be sorted in increasing order:
+    /// those are the elements between the `lower` and the `upper` quantile,
+    /// according to the definition in [`quantile_mut`](#tymethod.quantile_mut).
+    /// Elements before the view are smaller than or equal to all the
+    /// elements in the view, elements after the view are greater than or
+    /// equal to them.
+    ///
+    /// The order of the elements within the view is undefined: it relies
+    /// on two calls to [`sorted_get_mut`], without fully sorting the array.
+    ///
+    /// The view is empty if the array is empty or if no element is indexed
+    /// between the two quantiles.
+    ///
+    /// **Panics** if `lower` or `upper` are not in `[0, 1]` or if `lower >= upper`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Quantile1dExt;
+    ///
+    /// let mut a = array![9, -50, 3, 1, 7, 5, 100, 2, 8, 4, 6];
+    /// let mut inliers = a.trim_to_quantile_range_mut(0.1, 0.9).to_vec();
+    /// inliers.sort();
+    /// assert_eq!(inliers, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    ///
+    /// [`sorted_get_mut`]: trait.Sort1dExt.html#tymethod.sorted_get_mut
+    fn trim_to_quantile_range_mut(&mut self, lower: f64, upper: f64) -> ArrayViewMut1<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut;
+
+    /// Return count, mean, standard deviation, minimum, quartiles and maximum
+    /// of the data, in the spirit of pandas' `Series.describe()`.
+    ///
+    /// The statistics are the same computed by
+    /// [`describe_axis_mut`](trait.QuantileExt.html#tymethod.describe_axis_mut)
+    /// for each lane: the standard deviation is the sample standard deviation
+    /// (`ddof = 1`), while the quartiles are computed with [`Linear`] interpolation.
+    /// Minimum, quartiles and maximum are selected together, with
+    /// [`quantiles_mut`](#tymethod.quantiles_mut).
+    ///
+    /// The array is shuffled **in place**, as in [`quantile_mut`](#tymethod.quantile_mut).
+    ///
+    /// The elements must be both `Float` and `Ord`: plain `f32`/`f64` do not
+    /// qualify, use the noisy float types (e.g. `N64` or `R64`) instead,
+    /// converting the array with `mapv(n64)` if needed.
+    ///
+    /// Returns `Err(EmptyInput)` if the array is empty. If the array has a
+    /// single element the sample standard deviation is not defined and
+    /// `std` is `None`.
+    ///
+    /// **Panics** if the type cast of the number of elements from `usize`
+    /// to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// extern crate noisy_float;
+    /// use ndarray::array;
+    /// use ndarray_stats::Quantile1dExt;
+    /// use noisy_float::types::n64;
+    ///
+    /// let mut a = array![4., 1., 3., 2., 5.].mapv(n64);
+    /// let summary = a.describe_mut().unwrap();
+    /// assert_eq!(summary.count, 5);
+    /// assert_eq!(summary.mean, n64(3.));
+    /// assert_eq!(summary.first_quartile, n64(2.));
+    /// assert_eq!(summary.max, n64(5.));
+    /// ```
+    ///
+    /// [`Linear`]: interpolate/struct.Linear.html
+    fn describe_mut(&mut self) -> Result<Summary<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Ord,
+        S: DataMut;
+
+    /// Return the running median of the data: the `i`-th element of the
+    /// returned array is the median of `self[..=i]`, the first `i + 1` elements.
+    ///
+    /// The median is computed using `I` to interpolate, as in
+    /// [`quantile_mut`](#tymethod.quantile_mut) with `q=0.5`.
+    ///
+    /// The elements seen so far are kept in two binary heaps (a max-heap for
+    /// the smaller half, a min-heap for the larger half): the complexity is
+    /// O(`n` log `n`), where `n` is the number of elements in the array, and
+    /// a copy of the array is allocated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Midpoint, Quantile1dExt};
+    ///
+    /// let a = array![5, 15, 1, 3, 8];
+    /// assert_eq!(a.running_median::<Midpoint>(), array![5, 10, 5, 4, 5]);
+    /// ```
+    fn running_median<I>(&self) -> Array1<A>
+    where
+        A: Ord + Clone,
+        I: Interpolate<A>;
+
+    /// Return the qth quantile of the data as a `f64`.
+    ///
+    /// The data points surrounding the quantile are converted to `f64`
+    /// before the interpolation takes place: this is useful for arrays of integers,
+    /// where interpolating in `A` would truncate the result (e.g. using
+    /// [`Linear`], `quantile_mut` returns `1` as the median of `[1, 2]`,
+    /// while `quantile_f64_mut` returns `1.5`).
+    ///
+    /// See [`quantile_mut`](#tymethod.quantile_mut) for details.
+    ///
+    /// Returns `None` if the array is empty.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive) or if
+    /// the conversion of an element from `A` to `f64` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Linear, Quantile1dExt};
+    ///
+    /// let mut a = array![2, 1];
+    /// assert_eq!(a.quantile_f64_mut::<Linear>(0.5), Some(1.5));
+    /// ```
+    ///
+    /// [`Linear`]: interpolate/struct.Linear.html
+    fn quantile_f64_mut<I>(&mut self, q: f64) -> Option<f64>
+    where
+        A: Ord + Clone + ToPrimitive,
+        S: DataMut,
+        I: Interpolate<f64>;
+
+    /// Finds the index of the minimum of the array, skipping NaN values.
+    ///
+    /// If the minimum appears multiple times, the index of its first
+    /// occurrence is returned.
+    ///
+    /// Returns `None` if the array is empty or if all its values are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Quantile1dExt;
+    /// use std::f64;
+    ///
+    /// let a = array![f64::NAN, 3., -1., f64::NAN];
+    /// assert_eq!(a.argmin_skipnan(), Some(2));
+    /// ```
+    fn argmin_skipnan(&self) -> Option<usize>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord;
+
+    /// Finds the index of the maximum of the array, skipping NaN values.
+    ///
+    /// If the maximum appears multiple times, the index of its first
+    /// occurrence is returned.
+    ///
+    /// Returns `None` if the array is empty or if all its values are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Quantile1dExt;
+    /// use std::f64;
+    ///
+    /// let a = array![f64::NAN, 3., -1., f64::NAN];
+    /// assert_eq!(a.argmax_skipnan(), Some(1));
+    /// ```
+    fn argmax_skipnan(&self) -> Option<usize>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord;
 }
 
 impl<A, S> Quantile1dExt<A, S> for ArrayBase<S, Ix1>
@@ -439,5 +1149,420 @@ impl<A, S> Quantile1dExt<A, S> for ArrayBase<S, Ix1>
             Some(self.quantile_axis_mut::<I>(Axis(0), q).into_scalar())
         }
     }
+
+    fn quantile_ordinal_mut<I>(&mut self, q: f64) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: OrdinalInterpolate<A>,
+    {
+        self.quantile_mut::<I>(q)
+    }
+
+    fn quantiles_mut<I>(&mut self, qs: &[f64]) -> Option<Array1<A>>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let len = self.len();
+        let mut indexes = Vec::with_capacity(2 * qs.len());
+        for &q in qs {
+            assert!((0. ..=1.).contains(&q));
+            if I::needs_lower(q, len) {
+                indexes.push(I::lower_index(q, len));
+            }
+            if I::needs_higher(q, len) {
+                indexes.push(I::higher_index(q, len));
+            }
+        }
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        // Once `self[index]` has been selected, the following elements are all
+        // greater than or equal to it: the next index can be selected among them.
+        let mut values = Vec::with_capacity(indexes.len());
+        let mut start = 0;
+        for &index in &indexes {
+            values.push(self.slice_mut(s![start..]).sorted_get_mut(index - start));
+            start = index + 1;
+        }
+        let value_at = |index: usize| {
+            let position = indexes.binary_search(&index).unwrap();
+            arr0(values[position].clone())
+        };
+
+        let quantiles = qs.iter().map(|&q| {
+            let lower = if I::needs_lower(q, len) {
+                Some(value_at(I::lower_index(q, len)))
+            } else {
+                None
+            };
+            let higher = if I::needs_higher(q, len) {
+                Some(value_at(I::higher_index(q, len)))
+            } else {
+                None
+            };
+            I::interpolate(lower, higher, q, len).into_scalar()
+        });
+        Some(quantiles.collect())
+    }
+
+    fn first_quartile_mut<I>(&mut self) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        self.quantile_mut::<I>(0.25)
+    }
+
+    fn third_quartile_mut<I>(&mut self) -> Option<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        self.quantile_mut::<I>(0.75)
+    }
+
+    fn quartiles_mut<I>(&mut self) -> Option<(A, A, A)>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        let quartiles = self.quantiles_mut::<I>(&[0.25, 0.5, 0.75])?;
+        Some((quartiles[0].clone(), quartiles[1].clone(), quartiles[2].clone()))
+    }
+
+    fn winsorize_mut<I>(&mut self, lower: f64, upper: f64)
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        assert!((0. <= lower) && (lower < upper) && (upper <= 1.),
+                "Invalid quantiles: expected `0 <= lower < upper <= 1`, got lower = {}, \
+                upper = {}.", lower, upper);
+        let cuts = match self.quantiles_mut::<I>(&[lower, upper]) {
+            Some(cuts) => cuts,
+            None => return,
+        };
+        let (low, high) = (&cuts[0], &cuts[1]);
+        self.map_inplace(|x| {
+            if *x < *low {
+                *x = low.clone();
+            } else if *x > *high {
+                *x = high.clone();
+            }
+        });
+    }
+
+    fn trim_to_quantile_range_mut(&mut self, lower: f64, upper: f64) -> ArrayViewMut1<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+    {
+        assert!((0. <= lower) && (lower < upper) && (upper <= 1.),
+                "Invalid quantiles: expected `0 <= lower < upper <= 1`, got lower = {}, \
+                upper = {}.", lower, upper);
+        let n = self.len();
+        if n == 0 {
+            return self.view_mut();
+        }
+        let lo = (((n - 1) as f64) * lower).ceil() as usize;
+        let hi = (((n - 1) as f64) * upper).floor() as usize + 1;
+        if lo >= hi {
+            return self.slice_mut(s![lo..lo]);
+        }
+        self.sorted_get_mut(lo);
+        if hi - lo > 1 {
+            // Elements after `lo` are all greater than or equal to `self[lo]`
+            self.slice_mut(s![lo + 1..]).sorted_get_mut(hi - lo - 2);
+        }
+        self.slice_mut(s![lo..hi])
+    }
+
+    fn describe_mut(&mut self) -> Result<Summary<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + Ord,
+        S: DataMut,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(describe_lane_mut(self.view_mut()))
+    }
+
+    fn running_median<I>(&self) -> Array1<A>
+    where
+        A: Ord + Clone,
+        I: Interpolate<A>,
+    {
+        // `smaller` holds the `ceil(len / 2)` smallest elements seen so far,
+        // `larger` the others: the median is found at the top of the heaps.
+        let mut smaller: BinaryHeap<A> = BinaryHeap::with_capacity(self.len() / 2 + 1);
+        let mut larger: BinaryHeap<cmp::Reverse<A>> = BinaryHeap::with_capacity(self.len() / 2);
+        let q = 0.5;
+        self.iter()
+            .enumerate()
+            .map(|(i, x)| {
+                match smaller.peek() {
+                    Some(top) if x > top => larger.push(cmp::Reverse(x.clone())),
+                    _ => smaller.push(x.clone()),
+                }
+                if smaller.len() > larger.len() + 1 {
+                    larger.push(cmp::Reverse(smaller.pop().unwrap()));
+                } else if larger.len() > smaller.len() {
+                    smaller.push(larger.pop().unwrap().0);
+                }
+                let len = i + 1;
+                let value_at = |index: usize| {
+                    if index < smaller.len() {
+                        smaller.peek().unwrap().clone()
+                    } else {
+                        larger.peek().unwrap().0.clone()
+                    }
+                };
+                let lower = if I::needs_lower(q, len) {
+                    Some(arr0(value_at(I::lower_index(q, len))))
+                } else {
+                    None
+                };
+                let higher = if I::needs_higher(q, len) {
+                    Some(arr0(value_at(I::higher_index(q, len))))
+                } else {
+                    None
+                };
+                I::interpolate(lower, higher, q, len).into_scalar()
+            })
+            .collect()
+    }
+
+    fn quantile_f64_mut<I>(&mut self, q: f64) -> Option<f64>
+    where
+        A: Ord + Clone + ToPrimitive,
+        S: DataMut,
+        I: Interpolate<f64>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        assert!((0. ..=1.).contains(&q));
+        let to_f64 = |x: A| {
+            arr0(x.to_f64().expect("Converting elements to `f64` must not fail."))
+        };
+        let mut lower = None;
+        let mut higher = None;
+        let len = self.len();
+        if I::needs_lower(q, len) {
+            let lower_index = I::lower_index(q, len);
+            lower = Some(to_f64(self.sorted_get_mut(lower_index)));
+            if I::needs_higher(q, len) {
+                let higher_index = I::higher_index(q, len);
+                let relative_higher_index = higher_index - lower_index;
+                higher = Some(to_f64(
+                    self.slice_mut(s![lower_index..])
+                        .sorted_get_mut(relative_higher_index),
+                ));
+            };
+        } else {
+            higher = Some(to_f64(self.sorted_get_mut(I::higher_index(q, len))));
+        };
+        Some(I::interpolate(lower, higher, q, len).into_scalar())
+    }
+
+    fn argmin_skipnan(&self) -> Option<usize>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord,
+    {
+        argbest_skipnan(self, cmp::Ordering::Less)
+    }
+
+    fn argmax_skipnan(&self) -> Option<usize>
+    where
+        A: MaybeNan,
+        A::NotNan: Ord,
+    {
+        argbest_skipnan(self, cmp::Ordering::Greater)
+    }
+}
+
+/// Quantile methods for 2-D arrays.
+pub trait Quantile2dExt<A, S>
+    where
+        S: Data<Elem = A>,
+{
+    /// Return the qth quantile of each row of a 2-dimensional array, e.g.
+    /// a batch of same-length observations stored one per row.
+    ///
+    /// The returned array has length equal to the number of rows.
+    /// It is equivalent to [`quantile_axis_mut`] along `Axis(1)`:
+    /// see its documentation for details on `q` and on the
+    /// interpolation strategy `I`.
+    ///
+    /// Each row is shuffled **in place**.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive) or
+    /// if the rows are empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Lower, Quantile2dExt};
+    ///
+    /// let mut batch = array![[3, 1, 2], [10, 30, 20]];
+    /// assert_eq!(batch.quantile_rows_mut::<Lower>(0.5), array![2, 20]);
+    /// ```
+    ///
+    /// [`quantile_axis_mut`]: trait.QuantileExt.html#tymethod.quantile_axis_mut
+    fn quantile_rows_mut<I>(&mut self, q: f64) -> Array1<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>;
+}
+
+impl<A, S> Quantile2dExt<A, S> for ArrayBase<S, Ix2>
+    where
+        S: Data<Elem = A>,
+{
+    fn quantile_rows_mut<I>(&mut self, q: f64) -> Array1<A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+        I: Interpolate<A>,
+    {
+        self.quantile_axis_mut::<I>(Axis(1), q)
+    }
+}
+
+/// Return the median of the elements of two arrays, `a` and `b`,
+/// **both sorted in increasing order**, without merging them.
+///
+/// The median is the quantile for `q=0.5` of all the `m + n` elements of
+/// `a` and `b` (`m` and `n` being their lengths), computed with `I` to
+/// interpolate: see [`quantile_mut`] for details.
+///
+/// The two middle elements are found with a binary search on the shorter array:
+/// the complexity is O(log(min(`m`, `n`))).
+///
+/// Returns `None` if both arrays are empty.
+///
+/// **Warning**: the result is meaningless if `a` or `b` is not sorted
+/// in increasing order. The precondition is not checked.
+///
+/// # Example
+///
+/// ```
+/// extern crate ndarray;
+/// extern crate ndarray_stats;
+/// use ndarray::array;
+/// use ndarray_stats::{interpolate::Midpoint, median_of_two_sorted};
+///
+/// let a = array![1, 3, 8, 9];
+/// let b = array![2, 4, 15];
+/// assert_eq!(median_of_two_sorted::<Midpoint, _>(&a.view(), &b.view()), Some(4));
+/// let c = array![10];
+/// assert_eq!(median_of_two_sorted::<Midpoint, _>(&a.view(), &c.view()), Some(8));
+/// ```
+///
+/// [`quantile_mut`]: trait.Quantile1dExt.html#tymethod.quantile_mut
+pub fn median_of_two_sorted<I, A>(a: &ArrayView1<A>, b: &ArrayView1<A>) -> Option<A>
+where
+    A: Ord + Clone,
+    I: Interpolate<A>,
+{
+    let len = a.len() + b.len();
+    if len == 0 {
+        return None;
+    }
+    let q = 0.5;
+    let lower_index = I::lower_index(q, len);
+    let (lower_value, next_value) = split_two_sorted(a, b, lower_index + 1);
+    let lower = if I::needs_lower(q, len) {
+        Some(arr0(lower_value.clone()))
+    } else {
+        None
+    };
+    let higher = if I::needs_higher(q, len) {
+        if I::higher_index(q, len) == lower_index {
+            Some(arr0(lower_value))
+        } else {
+            next_value.map(arr0)
+        }
+    } else {
+        None
+    };
+    Some(I::interpolate(lower, higher, q, len).into_scalar())
+}
+
+/// Split the elements of the sorted arrays `a` and `b` in the smallest
+/// `left_len` ones and the others, returning the largest element of the
+/// first group and the smallest element of the second group (if any).
+///
+/// **Panics** if `left_len` is zero or greater than `a.len() + b.len()`.
+fn split_two_sorted<A>(a: &ArrayView1<A>, b: &ArrayView1<A>, left_len: usize) -> (A, Option<A>)
+where
+    A: Ord + Clone,
+{
+    assert!(0 < left_len && left_len <= a.len() + b.len());
+    // Binary search on the shorter array
+    let (a, b) = if a.len() <= b.len() { (a.view(), b.view()) } else { (b.view(), a.view()) };
+    let (m, n) = (a.len(), b.len());
+    // `i` elements are taken from `a`, `j = left_len - i` from `b`
+    let mut lo = left_len.saturating_sub(n);
+    let mut hi = cmp::min(m, left_len);
+    let (i, j) = loop {
+        let i = (lo + hi) / 2;
+        let j = left_len - i;
+        if i < m && j > 0 && b[j - 1] > a[i] {
+            lo = i + 1;
+        } else if i > 0 && j < n && a[i - 1] > b[j] {
+            hi = i - 1;
+        } else {
+            break (i, j);
+        }
+    };
+    let max_left = match (i, j) {
+        (0, _) => b[j - 1].clone(),
+        (_, 0) => a[i - 1].clone(),
+        _ => cmp::max(&a[i - 1], &b[j - 1]).clone(),
+    };
+    let min_right = match (i < m, j < n) {
+        (true, true) => Some(cmp::min(&a[i], &b[j]).clone()),
+        (true, false) => Some(a[i].clone()),
+        (false, true) => Some(b[j].clone()),
+        (false, false) => None,
+    };
+    (max_left, min_right)
+}
+
+/// Returns the index of the first non-NaN element `x` such that no other
+/// non-NaN element `y` satisfies `y.cmp(x) == ordering`.
+fn argbest_skipnan<A, S>(array: &ArrayBase<S, Ix1>, ordering: cmp::Ordering) -> Option<usize>
+where
+    S: Data<Elem = A>,
+    A: MaybeNan,
+    A::NotNan: Ord,
+{
+    let mut best: Option<(usize, &A::NotNan)> = None;
+    for (index, elem) in array.iter().enumerate() {
+        if let Some(elem) = elem.try_as_not_nan() {
+            match best {
+                Some((_, best_elem)) if elem.cmp(best_elem) != ordering => {}
+                _ => best = Some((index, elem)),
+            }
+        }
+    }
+    best.map(|(index, _)| index)
 }
 
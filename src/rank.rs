@@ -0,0 +1,164 @@
+use ndarray::prelude::*;
+use ndarray::{Data, Zip};
+use std::cmp::Ordering;
+
+/// Method used to assign ranks to tied elements (i.e. equal elements)
+/// by [`rank_axis`].
+///
+/// The names match the ones used by SciPy's `rankdata`.
+///
+/// [`rank_axis`]: trait.RankExt.html#tymethod.rank_axis
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TieMethod {
+    /// Every tied element gets the average of the ranks they span.
+    Average,
+    /// Every tied element gets the smallest of the ranks they span.
+    Min,
+    /// Every tied element gets the largest of the ranks they span.
+    Max,
+    /// Every tied element gets the smallest of the ranks they span,
+    /// but the rank of the next distinct element is incremented by one
+    /// (instead of the number of tied elements).
+    Dense,
+    /// Tied elements get distinct ranks, in the order in which they appear.
+    Ordinal,
+}
+
+/// Extension trait for `ArrayBase` providing methods
+/// to compute the ranks of its elements.
+pub trait RankExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the ranks of the elements of each 1-dimensional lane along `axis`,
+    /// computed independently for each lane.
+    ///
+    /// The smallest element of a lane has rank `1`, the largest has rank
+    /// equal to the length of the lane (unless there are ties):
+    /// tied elements get their ranks according to `method`.
+    ///
+    /// Each lane is argsorted: the complexity is O(`m` log `m`) for each lane,
+    /// where `m` is the length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{RankExt, TieMethod};
+    ///
+    /// let a = array![[10, 30, 20, 30], [4, 3, 2, 1]];
+    /// assert_eq!(
+    ///     a.rank_axis(Axis(1), TieMethod::Average),
+    ///     array![[1., 3.5, 2., 3.5], [4., 3., 2., 1.]]
+    /// );
+    /// ```
+    fn rank_axis(&self, axis: Axis, method: TieMethod) -> Array<f64, D>
+    where
+        A: Ord;
+}
+
+impl<A, S, D> RankExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn rank_axis(&self, axis: Axis, method: TieMethod) -> Array<f64, D>
+    where
+        A: Ord,
+    {
+        let mut ranks = Array::zeros(self.raw_dim());
+        Zip::from(ranks.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .apply(|ranks, lane| rank_lane_by(&lane, ranks, method, |a, b| a.cmp(b)));
+        ranks
+    }
+}
+
+/// Write in `ranks` the ranks of the elements of `lane`, ordered by `compare`.
+///
+/// **Panics** if `lane` and `ranks` have different lengths.
+pub(crate) fn rank_lane_by<A, F>(
+    lane: &ArrayView1<A>,
+    mut ranks: ArrayViewMut1<f64>,
+    method: TieMethod,
+    mut compare: F,
+) where
+    F: FnMut(&A, &A) -> Ordering,
+{
+    assert_eq!(lane.len(), ranks.len());
+    let mut order: Vec<usize> = (0..lane.len()).collect();
+    // A stable sort keeps tied elements in order of appearance
+    order.sort_by(|&i, &j| compare(&lane[i], &lane[j]));
+    let mut start = 0;
+    let mut n_distinct = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && compare(&lane[order[end]], &lane[order[start]]) == Ordering::Equal {
+            end += 1;
+        }
+        n_distinct += 1;
+        // `order[start..end]` are tied, spanning ranks `start + 1..=end`
+        for (offset, &index) in order[start..end].iter().enumerate() {
+            ranks[index] = match method {
+                TieMethod::Average => (start + 1 + end) as f64 / 2.,
+                TieMethod::Min => (start + 1) as f64,
+                TieMethod::Max => end as f64,
+                TieMethod::Dense => n_distinct as f64,
+                TieMethod::Ordinal => (start + 1 + offset) as f64,
+            };
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_rank_axis_tie_methods() {
+        // Each row is ranked as SciPy's `rankdata` would, for every method
+        let a = array![[3, 1, 4, 1, 5], [9, 2, 6, 5, 3]];
+        assert_eq!(
+            a.rank_axis(Axis(1), TieMethod::Average),
+            array![[3., 1.5, 4., 1.5, 5.], [5., 1., 4., 3., 2.]]
+        );
+        assert_eq!(
+            a.rank_axis(Axis(1), TieMethod::Min),
+            array![[3., 1., 4., 1., 5.], [5., 1., 4., 3., 2.]]
+        );
+        assert_eq!(
+            a.rank_axis(Axis(1), TieMethod::Max),
+            array![[3., 2., 4., 2., 5.], [5., 1., 4., 3., 2.]]
+        );
+        assert_eq!(
+            a.rank_axis(Axis(1), TieMethod::Dense),
+            array![[2., 1., 3., 1., 4.], [5., 1., 4., 3., 2.]]
+        );
+        assert_eq!(
+            a.rank_axis(Axis(1), TieMethod::Ordinal),
+            array![[3., 1., 4., 2., 5.], [5., 1., 4., 3., 2.]]
+        );
+    }
+
+    #[test]
+    fn test_rank_axis_along_columns() {
+        let a = array![[3, 7], [1, 7], [2, 7]];
+        assert_eq!(
+            a.rank_axis(Axis(0), TieMethod::Average),
+            array![[3., 2.], [1., 2.], [2., 2.]]
+        );
+    }
+
+    #[test]
+    fn test_rank_axis_of_empty_lanes() {
+        let a = Array2::<i32>::zeros((2, 0));
+        assert_eq!(a.rank_axis(Axis(1), TieMethod::Min).dim(), (2, 0));
+    }
+}
@@ -1,3 +1,4 @@
+use interpolate::Interpolate;
 use ndarray::prelude::*;
 use ndarray::{s, Data, DataMut};
 use rand::prelude::*;
@@ -55,6 +56,219 @@ where
     where
         A: Ord + Clone,
         S: DataMut;
+
+    /// Partition **in place** the elements of `self` with index in `lo..hi`
+    /// around `self[pivot_index]`, leaving all other elements untouched.
+    ///
+    /// It behaves as [`partition_mut`](#tymethod.partition_mut) on
+    /// `self.slice_mut(s![lo..hi])`, but `pivot_index` and the returned
+    /// index are absolute indices into `self`: no new view is created,
+    /// which is convenient for repeated partitioning (e.g. quickselect).
+    ///
+    /// Complexity: O(`hi - lo`).
+    ///
+    /// **Panics** unless `lo <= pivot_index < hi <= n`, where `n` is
+    /// the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// let mut a = array![9, 5, 7, 3, 6, 0];
+    /// let partition_index = a.partition_range_mut(1, 5, 2);
+    /// assert_eq!(partition_index, 4);
+    /// assert_eq!(a[partition_index], 7);
+    /// // elements outside of `1..5` are untouched
+    /// assert_eq!((a[0], a[5]), (9, 0));
+    /// ```
+    fn partition_range_mut(&mut self, lo: usize, hi: usize, pivot_index: usize) -> usize
+    where
+        A: Ord + Clone,
+        S: DataMut;
+
+    /// Same as [`partition_mut`], but it also returns the number of
+    /// comparisons and swaps performed by the partition algorithm.
+    ///
+    /// The elements are rearranged exactly as [`partition_mut`] would:
+    /// the counts are only observed, e.g. to check empirically the average
+    /// number of swaps or to compare pivot choices.
+    ///
+    /// **Panics** if `pivot_index` is greater than or equal to `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// let mut a = array![3, 7, 1, 8, 2, 9, 4];
+    /// let (partition_index, stats) = a.partition_mut_instrumented(0);
+    /// assert_eq!(partition_index, 2);
+    /// // 7 and 2 are the only pair of elements on the wrong side of the pivot
+    /// assert_eq!(stats.swaps, 1);
+    /// ```
+    ///
+    /// [`partition_mut`]: #tymethod.partition_mut
+    fn partition_mut_instrumented(&mut self, pivot_index: usize) -> (usize, PartitionStats)
+    where
+        A: Ord + Clone,
+        S: DataMut;
+
+    /// Sort `self` **in place** in increasing order and return a [`SortedView`]
+    /// on it, to answer many order-based queries (quantiles, ECDF, etc.)
+    /// without having to select or sort the data again.
+    ///
+    /// Complexity: O(`n` log `n`), where `n` is the number of elements in the array.
+    ///
+    /// [`SortedView`]: struct.SortedView.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::{interpolate::Lower, Sort1dExt};
+    ///
+    /// let mut a = array![5, 1, 4, 2, 3];
+    /// let sorted = a.sort_into_view_mut();
+    /// assert_eq!(sorted.quantile::<Lower>(0.5), Some(3));
+    /// assert_eq!(sorted.ecdf(&2), 0.4);
+    /// ```
+    fn sort_into_view_mut(&mut self) -> SortedView<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut;
+
+    /// Rearrange `self` **in place** so that the elements with index in
+    /// `lo..hi` are the ones that would occupy those positions if the array
+    /// were sorted in increasing order, sorted, and return a view on them.
+    ///
+    /// All elements with an index smaller than `lo` are smaller than or equal
+    /// to the elements in the range, while all elements with an index greater
+    /// than or equal to `hi` are greater than or equal to them.
+    /// No other assumptions should be made on the ordering of the
+    /// elements outside of the range after this computation.
+    ///
+    /// Two selections (see [`sorted_get_mut`](#tymethod.sorted_get_mut)) isolate
+    /// the range, then only its elements are sorted. Let `n` be the number of
+    /// elements in the array and `m = hi - lo`:
+    /// - average case complexity: O(`n` + `m` log `m`);
+    /// - worst case complexity: O(`n`^2 + `m` log `m`).
+    ///
+    /// **Panics** if `lo` is greater than `hi` or `hi` is greater than `n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// let mut a = array![9, 2, 7, 4, 0, 5, 8, 1, 6, 3];
+    /// assert_eq!(a.sorted_range_mut(3, 6), array![3, 4, 5]);
+    /// ```
+    fn sorted_range_mut(&mut self, lo: usize, hi: usize) -> ArrayViewMut1<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut;
+
+    /// Return `true` if the elements of the array are sorted in
+    /// non-decreasing order, i.e. `self[i] <= self[i + 1]` for every `i`
+    /// (equal adjacent elements are allowed).
+    ///
+    /// Empty arrays and arrays with one element are sorted.
+    ///
+    /// Complexity: O(`n`), where `n` is the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// assert!(array![1, 2, 2, 5].is_sorted());
+    /// assert!(!array![1, 3, 2].is_sorted());
+    /// ```
+    fn is_sorted(&self) -> bool
+    where
+        A: Ord;
+
+    /// Return `true` if `in_order(&self[i], &self[i + 1])` holds for
+    /// every pair of adjacent elements of the array.
+    ///
+    /// For example, `is_sorted_by(|a, b| a >= b)` checks that the array
+    /// is sorted in non-increasing order. There is no `Ord` requirement
+    /// on the elements: `is_sorted_by(|a, b| a <= b)` can be used with floats.
+    ///
+    /// Complexity: O(`n`), where `n` is the number of elements in the array.
+    fn is_sorted_by<F>(&self, in_order: F) -> bool
+    where
+        F: FnMut(&A, &A) -> bool;
+
+    /// Return the indices that would sort the array in increasing order,
+    /// i.e. the permutation `p` such that `self[p[0]] <= self[p[1]] <= ...`.
+    ///
+    /// The sort is **stable**: the indices of equal elements appear in
+    /// increasing order. Use [`argsort_unstable`](#tymethod.argsort_unstable)
+    /// if the order of the indices of equal elements does not matter.
+    ///
+    /// Complexity: O(`n` log `n`), where `n` is the number of elements in the array.
+    /// It relies on `slice::sort_by`, which allocates an auxiliary buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// let a = array![3, 1, 2, 1];
+    /// assert_eq!(a.argsort(), array![1, 3, 2, 0]);
+    /// ```
+    fn argsort(&self) -> Array1<usize>
+    where
+        A: Ord;
+
+    /// Return the indices that would sort the array in increasing order,
+    /// without preserving the relative order of the indices of equal elements.
+    ///
+    /// It relies on `slice::sort_unstable_by` (a pattern-defeating quicksort)
+    /// rather than on this crate's quickselect, and it does not allocate any
+    /// auxiliary buffer besides the returned indices.
+    /// It is usually faster than [`argsort`](#tymethod.argsort), especially
+    /// when there are many equal elements.
+    /// It should not be used when ties have to be broken by position
+    /// (e.g. [`TieMethod::Ordinal`] ranks).
+    ///
+    /// Complexity: O(`n` log `n`), where `n` is the number of elements in the array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Sort1dExt;
+    ///
+    /// let a = array![30, 10, 20];
+    /// assert_eq!(a.argsort_unstable(), array![1, 2, 0]);
+    /// ```
+    ///
+    /// [`TieMethod::Ordinal`]: enum.TieMethod.html#variant.Ordinal
+    fn argsort_unstable(&self) -> Array1<usize>
+    where
+        A: Ord;
 }
 
 impl<A, S> Sort1dExt<A, S> for ArrayBase<S, Ix1>
@@ -67,19 +281,23 @@ where
         S: DataMut,
     {
         let n = self.len();
-        if n == 1 {
-            self[0].clone()
-        } else {
-            let mut rng = thread_rng();
-            let pivot_index = rng.gen_range(0, n);
-            let partition_index = self.partition_mut(pivot_index);
+        assert!(i < n, "Index out of bounds: the index is {}, the length is {}.", i, n);
+        let mut rng = thread_rng();
+        // The desired element is always in `self[lo..hi]`
+        let mut lo = 0;
+        let mut hi = n;
+        loop {
+            if hi - lo == 1 {
+                return self[lo].clone();
+            }
+            let pivot_index = rng.gen_range(lo, hi);
+            let partition_index = self.partition_range_mut(lo, hi, pivot_index);
             if i < partition_index {
-                self.slice_mut(s![..partition_index]).sorted_get_mut(i)
+                hi = partition_index;
             } else if i == partition_index {
-                self[i].clone()
+                return self[i].clone();
             } else {
-                self.slice_mut(s![partition_index + 1..])
-                    .sorted_get_mut(i - (partition_index + 1))
+                lo = partition_index + 1;
             }
         }
     }
@@ -89,36 +307,282 @@ where
         A: Ord + Clone,
         S: DataMut,
     {
-        let pivot_value = self[pivot_index].clone();
-        self.swap(pivot_index, 0);
         let n = self.len();
-        let mut i = 1;
-        let mut j = n - 1;
+        self.partition_range_mut(0, n, pivot_index)
+    }
+
+    fn partition_range_mut(&mut self, lo: usize, hi: usize, pivot_index: usize) -> usize
+    where
+        A: Ord + Clone,
+        S: DataMut,
+    {
+        partition_range(self, lo, hi, pivot_index, &mut PartitionStats::default())
+    }
+
+    fn partition_mut_instrumented(&mut self, pivot_index: usize) -> (usize, PartitionStats)
+    where
+        A: Ord + Clone,
+        S: DataMut,
+    {
+        let n = self.len();
+        let mut stats = PartitionStats::default();
+        let partition_index = partition_range(self, 0, n, pivot_index, &mut stats);
+        (partition_index, stats)
+    }
+
+    fn sort_into_view_mut(&mut self) -> SortedView<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+    {
+        sort_mut(self);
+        SortedView { data: self.view() }
+    }
+
+    fn sorted_range_mut(&mut self, lo: usize, hi: usize) -> ArrayViewMut1<'_, A>
+    where
+        A: Ord + Clone,
+        S: DataMut,
+    {
+        let n = self.len();
+        assert!(lo <= hi && hi <= n,
+                "Invalid range: expected `lo <= hi <= n`, got lo = {}, hi = {}, n = {}.",
+                lo, hi, n);
+        if hi - lo > 0 {
+            self.sorted_get_mut(lo);
+        }
+        if hi - lo > 1 {
+            self.slice_mut(s![lo + 1..]).sorted_get_mut(hi - lo - 2);
+            sort_mut(&mut self.slice_mut(s![lo + 1..hi - 1]));
+        }
+        self.slice_mut(s![lo..hi])
+    }
+
+    fn is_sorted(&self) -> bool
+    where
+        A: Ord,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    fn is_sorted_by<F>(&self, mut in_order: F) -> bool
+    where
+        F: FnMut(&A, &A) -> bool,
+    {
+        (1..self.len()).all(|i| in_order(&self[i - 1], &self[i]))
+    }
+
+    fn argsort(&self) -> Array1<usize>
+    where
+        A: Ord,
+    {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_by(|&i, &j| self[i].cmp(&self[j]));
+        Array1::from_vec(indices)
+    }
+
+    fn argsort_unstable(&self) -> Array1<usize>
+    where
+        A: Ord,
+    {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        indices.sort_unstable_by(|&i, &j| self[i].cmp(&self[j]));
+        Array1::from_vec(indices)
+    }
+}
+
+/// Hoare's partition of `array[lo..hi]` around `array[pivot_index]`,
+/// recording the comparisons and swaps it performs in `stats`.
+fn partition_range<A, S>(
+    array: &mut ArrayBase<S, Ix1>,
+    lo: usize,
+    hi: usize,
+    pivot_index: usize,
+    stats: &mut PartitionStats,
+) -> usize
+where
+    A: Ord + Clone,
+    S: DataMut<Elem = A>,
+{
+    assert!(lo <= pivot_index && pivot_index < hi && hi <= array.len(),
+            "Invalid range: expected `lo <= pivot_index < hi <= n`, got lo = {}, \
+            pivot_index = {}, hi = {}, n = {}.", lo, pivot_index, hi, array.len());
+    let pivot_value = array[pivot_index].clone();
+    if hi - lo == 1 {
+        // The scans below assume at least one element besides the pivot.
+        return lo;
+    }
+    array.swap(pivot_index, lo);
+    let mut i = lo + 1;
+    let mut j = hi - 1;
+    loop {
         loop {
-            loop {
-                if i > j {
-                    break;
-                }
-                if self[i] >= pivot_value {
-                    break;
-                }
-                i += 1;
+            if i > j {
+                break;
             }
-            while pivot_value <= self[j] {
-                if j == 1 {
-                    break;
-                }
-                j -= 1;
+            stats.comparisons += 1;
+            if array[i] >= pivot_value {
+                break;
             }
-            if i >= j {
+            i += 1;
+        }
+        loop {
+            stats.comparisons += 1;
+            if array[j] < pivot_value {
                 break;
-            } else {
-                self.swap(i, j);
-                i += 1;
-                j -= 1;
             }
+            if j == lo + 1 {
+                break;
+            }
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        } else {
+            array.swap(i, j);
+            stats.swaps += 1;
+            i += 1;
+            j -= 1;
+        }
+    }
+    array.swap(lo, i - 1);
+    i - 1
+}
+
+/// Operation counts of a partition, as returned by
+/// [`partition_mut_instrumented`].
+///
+/// [`partition_mut_instrumented`]: trait.Sort1dExt.html#tymethod.partition_mut_instrumented
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PartitionStats {
+    /// Number of comparisons between an element and the pivot.
+    pub comparisons: usize,
+    /// Number of swaps between an element smaller than the pivot and an element
+    /// greater than (or equal to) the pivot. The two moves that put the pivot
+    /// aside and then in its final position are not counted.
+    pub swaps: usize,
+}
+
+/// A 1-dimensional view on data sorted in increasing order,
+/// as returned by [`sort_into_view_mut`].
+///
+/// All queries rely on binary search: each of them has O(log `n`) complexity,
+/// where `n` is the number of elements in the view.
+///
+/// [`sort_into_view_mut`]: trait.Sort1dExt.html#tymethod.sort_into_view_mut
+#[derive(Clone, Debug)]
+pub struct SortedView<'a, A: 'a> {
+    data: ArrayView1<'a, A>,
+}
+
+impl<'a, A> SortedView<'a, A>
+where
+    A: Ord + Clone,
+{
+    /// Return the qth quantile of the data.
+    ///
+    /// See [`quantile_mut`] for the definition of quantiles and
+    /// the role of the interpolation strategy `I`.
+    ///
+    /// Returns `None` if the view is empty.
+    ///
+    /// **Panics** if `q` is not between `0.` and `1.` (inclusive).
+    ///
+    /// [`quantile_mut`]: trait.Quantile1dExt.html#tymethod.quantile_mut
+    pub fn quantile<I>(&self, q: f64) -> Option<A>
+    where
+        I: Interpolate<A>,
+    {
+        assert!((0. ..=1.).contains(&q));
+        let len = self.data.len();
+        if len == 0 {
+            return None;
+        }
+        let lower = if I::needs_lower(q, len) {
+            Some(arr0(self.data[I::lower_index(q, len)].clone()))
+        } else {
+            None
+        };
+        let higher = if I::needs_higher(q, len) {
+            Some(arr0(self.data[I::higher_index(q, len)].clone()))
+        } else {
+            None
+        };
+        Some(I::interpolate(lower, higher, q, len).into_scalar())
+    }
+
+    /// Return the percentile rank of `score` relative to the data,
+    /// as SciPy's `percentileofscore` (using its default `kind='rank'`).
+    ///
+    /// If `score` is not equal to any element, the result is the percentage
+    /// of elements smaller than `score`; otherwise, the rank of `score`
+    /// is the average of the ranks of the elements equal to it.
+    ///
+    /// Returns NaN if the view is empty.
+    pub fn percentile_of_score(&self, score: &A) -> f64 {
+        let smaller = self.count_less_than(score);
+        let smaller_or_equal = self.count_less_than_or_equal(score);
+        let correction = if smaller_or_equal > smaller { 1 } else { 0 };
+        ((smaller + smaller_or_equal + correction) as f64) * 50. / (self.data.len() as f64)
+    }
+
+    /// Return the [empirical cumulative distribution function]
+    /// of the data evaluated at `x`: the fraction of elements
+    /// smaller than or equal to `x`.
+    ///
+    /// Returns NaN if the view is empty.
+    ///
+    /// [empirical cumulative distribution function]: https://en.wikipedia.org/wiki/Empirical_distribution_function
+    pub fn ecdf(&self, x: &A) -> f64 {
+        (self.count_less_than_or_equal(x) as f64) / (self.data.len() as f64)
+    }
+
+    /// Return an immutable view on the sorted data.
+    pub fn view(&self) -> ArrayView1<'a, A> {
+        self.data
+    }
+
+    fn count_less_than(&self, x: &A) -> usize {
+        partition_point(self.data.len(), |i| self.data[i] < *x)
+    }
+
+    fn count_less_than_or_equal(&self, x: &A) -> usize {
+        partition_point(self.data.len(), |i| self.data[i] <= *x)
+    }
+}
+
+/// Sort `a` in place, in increasing order, using an unstable sorting algorithm.
+fn sort_mut<A, S>(a: &mut ArrayBase<S, Ix1>)
+where
+    A: Ord + Clone,
+    S: DataMut<Elem = A>,
+{
+    if let Some(slice) = a.as_slice_mut() {
+        slice.sort_unstable();
+        return;
+    }
+    let mut sorted = a.to_vec();
+    sorted.sort_unstable();
+    for (elem, value) in a.iter_mut().zip(sorted) {
+        *elem = value;
+    }
+}
+
+/// Given a predicate on `0..len` that is `true` for a (possibly empty) prefix
+/// and `false` afterwards, it returns the length of that prefix using binary search.
+pub(crate) fn partition_point<F>(len: usize, pred: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
         }
-        self.swap(0, i - 1);
-        i - 1
     }
+    low
 }
@@ -1,7 +1,8 @@
-use ndarray::{Data, Dimension, ArrayBase};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, RemoveAxis, Zip};
 use num_traits::{FromPrimitive, Float, Zero};
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Mul};
 use super::SummaryStatisticsExt;
+use axis_lanes;
 
 
 impl<A, S, D> SummaryStatisticsExt<A, S, D> for ArrayBase<S, D>
@@ -36,6 +37,139 @@ where
     {
         self.map(|x| x.ln()).mean().map(|x| x.exp())
     }
+
+    fn cumprod_axis(&self, axis: Axis) -> Array<A, D>
+        where
+            A: Clone + Mul<Output=A>,
+    {
+        let mut cumprod = self.to_owned();
+        for mut lane in cumprod.lanes_mut(axis) {
+            for i in 1..lane.len() {
+                lane[i] = lane[i - 1].clone() * lane[i].clone();
+            }
+        }
+        cumprod
+    }
+
+    fn count_where<F>(&self, predicate: F) -> usize
+        where
+            F: Fn(&A) -> bool,
+    {
+        self.fold(0, |count, x| if predicate(x) { count + 1 } else { count })
+    }
+
+    fn count_where_axis<F>(&self, axis: Axis, predicate: F) -> Array<usize, D::Smaller>
+        where
+            D: RemoveAxis,
+            F: Fn(&A) -> bool,
+    {
+        self.fold_axis(axis, 0, |&count, x| if predicate(x) { count + 1 } else { count })
+    }
+
+    fn mode_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where
+            A: Ord + Clone,
+            D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "The length of `axis` has to be strictly positive.");
+        let modes = axis_lanes(self, axis).map(|lane| {
+            let mut sorted = lane.to_vec();
+            sorted.sort_unstable();
+            let mut mode = &sorted[0];
+            let mut mode_count = 0;
+            let mut start = 0;
+            for end in 1..=sorted.len() {
+                if end == sorted.len() || sorted[end] != sorted[start] {
+                    // `sorted[start..end]` is a run of equal values: as values
+                    // are increasing, only strictly longer runs replace the mode
+                    if end - start > mode_count {
+                        mode = &sorted[start];
+                        mode_count = end - start;
+                    }
+                    start = end;
+                }
+            }
+            mode.clone()
+        }).collect();
+        Array::from_shape_vec(self.raw_dim().remove_axis(axis), modes).unwrap()
+    }
+
+    fn sum_skipnan(&self) -> A
+        where
+            A: Float,
+    {
+        self.fold(A::zero(), |sum, &x| if x.is_nan() { sum } else { sum + x })
+    }
+
+    fn sum_skipnan_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where
+            A: Float,
+            D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::zero(), |&sum, &x| if x.is_nan() { sum } else { sum + x })
+    }
+
+    fn mean_axis_skipnan(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<usize, D::Smaller>)
+        where
+            A: Float + FromPrimitive,
+            D: RemoveAxis,
+    {
+        let counts = self.fold_axis(axis, 0, |&count, &x| {
+            if x.is_finite() { count + 1 } else { count }
+        });
+        let mut means = self.fold_axis(axis, A::zero(), |&sum, &x| {
+            if x.is_finite() { sum + x } else { sum }
+        });
+        Zip::from(&mut means).and(&counts).apply(|mean, &count| {
+            *mean = if count == 0 {
+                A::nan()
+            } else {
+                *mean / A::from_usize(count).unwrap()
+            };
+        });
+        (means, counts)
+    }
+
+    fn sem(&self, ddof: A) -> A
+        where
+            A: Float + FromPrimitive,
+    {
+        let n = self.len();
+        if n == 0 {
+            return A::nan();
+        }
+        let n = A::from_usize(n).unwrap();
+        assert!(
+            !(ddof < A::zero() || ddof >= n),
+            "`ddof` must not be less than zero or greater than or equal to the length of the array",
+        );
+        let mean = self.fold(A::zero(), |sum, &x| sum + x) / n;
+        let sum_sq = self.fold(A::zero(), |sum, &x| sum + (x - mean) * (x - mean));
+        let std = (sum_sq / (n - ddof)).sqrt();
+        std / n.sqrt()
+    }
+
+    fn count_nonzero(&self) -> usize
+        where
+            A: Zero,
+    {
+        self.iter().filter(|x| !x.is_zero()).count()
+    }
+
+    fn fraction_nonzero(&self) -> f64
+        where
+            A: Zero,
+    {
+        self.count_nonzero() as f64 / self.len() as f64
+    }
+
+    fn count_nonzero_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+        where
+            A: Zero,
+            D: RemoveAxis,
+    {
+        self.fold_axis(axis, 0, |&count, x| if x.is_zero() { count } else { count + 1 })
+    }
 }
 
 #[cfg(test)]
@@ -44,7 +178,7 @@ mod tests {
     use std::f64;
     use approx::abs_diff_eq;
     use noisy_float::types::N64;
-    use ndarray::{array, Array1};
+    use ndarray::{array, Array1, Array2, Axis};
 
     #[test]
     fn test_means_with_nan_values() {
@@ -95,4 +229,173 @@ mod tests {
         abs_diff_eq!(a.harmonic_mean().unwrap(), expected_harmonic_mean, epsilon = f64::EPSILON);
         abs_diff_eq!(a.geometric_mean().unwrap(), expected_geometric_mean, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_cumprod_axis() {
+        let a = array![1, 2, 3, 4];
+        let cumprod = a.cumprod_axis(Axis(0));
+        assert_eq!(cumprod, array![1, 2, 6, 24]);
+        assert_eq!(cumprod[3], a.product());
+    }
+
+    #[test]
+    fn test_cumprod_axis_2d() {
+        let a = array![[1., 2.], [3., 4.], [0.5, 10.]];
+        assert_eq!(a.cumprod_axis(Axis(0)), array![[1., 2.], [3., 8.], [1.5, 80.]]);
+        assert_eq!(a.cumprod_axis(Axis(1)), array![[1., 2.], [3., 12.], [0.5, 5.]]);
+    }
+
+    #[test]
+    fn test_count_where() {
+        let a = array![[3, 8, -1], [6, 5, 12], [0, 7, 5]];
+        assert_eq!(a.count_where(|&x| x > 5), 4);
+        assert_eq!(a.count_where(|&x| x > 100), 0);
+        let empty: Array1<i32> = array![];
+        assert_eq!(empty.count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn test_count_where_axis() {
+        let a = array![[3, 8, -1], [6, 5, 12], [0, 7, 5]];
+        assert_eq!(a.count_where_axis(Axis(0), |&x| x > 5), array![1, 2, 1]);
+        assert_eq!(a.count_where_axis(Axis(1), |&x| x > 5), array![1, 2, 1]);
+        assert_eq!(a.count_where_axis(Axis(1), |&x| x >= 5), array![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_mode_axis() {
+        let a = array![
+            [4, 1, 3, 3],
+            [4, 2, 1, 3],
+            [1, 2, 1, 0],
+            [4, 1, 2, 0],
+        ];
+        // column 1 and 3 have ties: the smallest value wins
+        assert_eq!(a.mode_axis(Axis(0)), array![4, 1, 1, 0]);
+        assert_eq!(a.mode_axis(Axis(1)), array![3, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_mode_axis_3d() {
+        let a = array![
+            [[1, 2], [3, 4], [5, 6]],
+            [[1, 9], [4, 4], [6, 6]],
+            [[0, 9], [3, 8], [7, 5]],
+        ];
+        assert_eq!(a.mode_axis(Axis(0)), array![[1, 9], [3, 4], [5, 6]]);
+    }
+
+    #[test]
+    fn test_sum_skipnan() {
+        let a = array![[1.5, f64::NAN, 2.], [f64::NAN, -0.5, 4.]];
+        let finite: f64 = a.iter().filter(|x| x.is_finite()).sum();
+        assert_eq!(a.sum_skipnan(), finite);
+        assert_eq!(a.sum_skipnan_axis(Axis(0)), array![1.5, -0.5, 6.]);
+        assert_eq!(a.sum_skipnan_axis(Axis(1)), array![3.5, 3.5]);
+    }
+
+    #[test]
+    fn test_sum_skipnan_all_nan() {
+        let a = array![f64::NAN, f64::NAN];
+        assert_eq!(a.sum_skipnan(), 0.);
+        let a: Array1<f64> = array![];
+        assert_eq!(a.sum_skipnan(), 0.);
+    }
+
+    #[test]
+    fn test_sum_skipnan_propagates_infinities() {
+        let a = array![1., f64::NAN, f64::INFINITY];
+        assert_eq!(a.sum_skipnan(), f64::INFINITY);
+        let a = array![f64::NEG_INFINITY, f64::NAN, f64::INFINITY];
+        assert!(a.sum_skipnan().is_nan());
+    }
+
+    #[test]
+    fn test_mean_axis_skipnan() {
+        let a = array![
+            [1., f64::NAN, 3., f64::NAN],
+            [f64::NAN, f64::NAN, 5., 2.],
+            [4., f64::NAN, 7., -1.],
+        ];
+        let (means, counts) = a.mean_axis_skipnan(Axis(0));
+        assert_eq!(counts, array![2, 0, 3, 2]);
+        assert_eq!(means[0], 2.5);
+        assert!(means[1].is_nan());
+        assert_eq!(means[2], 5.);
+        assert_eq!(means[3], 0.5);
+
+        let (means, counts) = a.mean_axis_skipnan(Axis(1));
+        assert_eq!(counts, array![2, 2, 3]);
+        assert_eq!(means, array![2., 3.5, 10. / 3.]);
+    }
+
+    #[test]
+    fn test_mean_axis_skipnan_skips_infinite_values() {
+        let a = array![
+            [1., f64::INFINITY, f64::NEG_INFINITY],
+            [3., f64::NAN, f64::INFINITY],
+            [f64::NEG_INFINITY, 4., f64::NAN],
+        ];
+        let (means, counts) = a.mean_axis_skipnan(Axis(0));
+        assert_eq!(counts, array![2, 1, 0]);
+        assert_eq!(means[0], 2.);
+        assert_eq!(means[1], 4.);
+        assert!(means[2].is_nan());
+    }
+
+    #[test]
+    fn test_mean_axis_skipnan_with_empty_lanes() {
+        let a = Array2::<f64>::zeros((0, 2));
+        let (means, counts) = a.mean_axis_skipnan(Axis(0));
+        assert_eq!(counts, array![0, 0]);
+        assert!(means.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_count_nonzero() {
+        let a = array![[0., 1.5, 0., -2.], [0., 0., 3., f64::NAN], [-0., 4., 0., 0.]];
+        assert_eq!(a.count_nonzero(), 5);
+        assert_eq!(a.fraction_nonzero(), 5. / 12.);
+        assert_eq!(a.count_nonzero_axis(Axis(0)), array![0, 2, 1, 2]);
+        assert_eq!(a.count_nonzero_axis(Axis(1)), array![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_count_nonzero_of_empty_array() {
+        let a: Array1<i32> = array![];
+        assert_eq!(a.count_nonzero(), 0);
+        assert!(a.fraction_nonzero().is_nan());
+        let b = Array2::<i32>::zeros((0, 3));
+        assert_eq!(b.count_nonzero_axis(Axis(0)), array![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_sem() {
+        let a = array![[1.2, 3.4, 0.5], [2.8, 1.9, 4.1]];
+        let n = a.len() as f64;
+        let mean = a.sum() / n;
+        let var = a.fold(0., |acc, &x| acc + (x - mean) * (x - mean)) / (n - 1.);
+        assert!(abs_diff_eq!(a.sem(1.), var.sqrt() / n.sqrt(), epsilon = 1e-12));
+        assert!(abs_diff_eq!(a.t().sem(1.), a.sem(1.), epsilon = 1e-12));
+        assert!(array![5.].sem(0.) == 0.);
+    }
+
+    #[test]
+    fn test_sem_of_empty_array() {
+        let a: Array1<f64> = array![];
+        assert!(a.sem(1.).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sem_with_invalid_ddof() {
+        array![1., 2.].sem(2.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mode_axis_with_zero_length_axis() {
+        let a = Array2::<i32>::zeros((2, 0));
+        a.mode_axis(Axis(1));
+    }
 }
@@ -1,7 +1,7 @@
 //! Summary statistics (e.g. mean, variance, etc.).
-use ndarray::{Data, Dimension};
+use ndarray::{Array, Axis, Data, Dimension, RemoveAxis};
 use num_traits::{FromPrimitive, Float, Zero};
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Mul};
 
 /// Extension trait for `ArrayBase` providing methods
 /// to compute several summary statistics (e.g. mean, variance, etc.).
@@ -61,6 +61,246 @@ pub trait SummaryStatisticsExt<A, S, D>
         where
             A: Float + FromPrimitive;
 
+    /// Returns the cumulative product of the elements along `axis`:
+    /// the `i`-th element of each 1-dimensional lane is replaced by
+    /// the product of the first `i+1` elements of the lane.
+    ///
+    /// The product of all elements in the array is provided by `ndarray`
+    /// itself, as [`ArrayBase::product`].
+    ///
+    /// **Warning**: no check is performed on the magnitude of the partial products.
+    /// Integer types will overflow (panicking in debug builds, wrapping around
+    /// in release builds), while floating point products of many small values
+    /// underflow to zero. Consider working with sums of logarithms if that is a concern.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(
+    ///     a.cumprod_axis(Axis(1)),
+    ///     array![[1, 2, 6], [4, 20, 120]]
+    /// );
+    /// ```
+    ///
+    /// [`ArrayBase::product`]: https://docs.rs/ndarray/0.12/ndarray/struct.ArrayBase.html#method.product
+    fn cumprod_axis(&self, axis: Axis) -> Array<A, D>
+        where
+            A: Clone + Mul<Output=A>;
+
+    /// Returns the number of elements in the array satisfying `predicate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![[1, 7, 3], [9, 5, 6]];
+    /// assert_eq!(a.count_where(|&x| x > 5), 3);
+    /// ```
+    fn count_where<F>(&self, predicate: F) -> usize
+        where
+            F: Fn(&A) -> bool;
+
+    /// Returns the number of elements satisfying `predicate` in each
+    /// 1-dimensional lane along `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![[1, 7, 3], [9, 5, 6]];
+    /// assert_eq!(a.count_where_axis(Axis(1), |&x| x > 5), array![1, 2]);
+    /// ```
+    fn count_where_axis<F>(&self, axis: Axis, predicate: F) -> Array<usize, D::Smaller>
+        where
+            D: RemoveAxis,
+            F: Fn(&A) -> bool;
+
+    /// Returns the [mode](https://en.wikipedia.org/wiki/Mode_(statistics))
+    /// of each 1-dimensional lane along `axis`, i.e. its most frequent value.
+    ///
+    /// If multiple values are the most frequent in a lane, the smallest one is returned.
+    ///
+    /// A sorted copy of each lane is scanned to count the occurrences of each
+    /// value: the complexity is O(`m` log `m`) for each lane, where `m`
+    /// is the length of `axis`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the length of `axis` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let votes = array![[1, 0, 2], [1, 2, 2], [0, 2, 1]];
+    /// assert_eq!(votes.mode_axis(Axis(0)), array![1, 2, 2]);
+    /// ```
+    fn mode_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where
+            A: Ord + Clone,
+            D: RemoveAxis;
+
+    /// Returns the sum of all elements in the array, skipping NaN values.
+    ///
+    /// Infinities are **not** skipped: if the array contains `+∞` and `-∞`
+    /// the result is NaN, otherwise it is `±∞` if the array contains `±∞`.
+    ///
+    /// Returns `0` if the array is empty or all its elements are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    /// use std::f64;
+    ///
+    /// let a = array![[1., f64::NAN], [2., 3.]];
+    /// assert_eq!(a.sum_skipnan(), 6.);
+    /// ```
+    fn sum_skipnan(&self) -> A
+        where
+            A: Float;
+
+    /// Returns the sum of the elements of each 1-dimensional lane along `axis`,
+    /// skipping NaN values.
+    ///
+    /// See [`sum_skipnan`](#tymethod.sum_skipnan) for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn sum_skipnan_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+        where
+            A: Float,
+            D: RemoveAxis;
+
+    /// Returns the arithmetic mean of the elements of each 1-dimensional lane
+    /// along `axis`, skipping NaN and infinite values, together with the number
+    /// of finite values that contributed to each mean.
+    ///
+    /// The mean of a lane without any finite value is NaN, with a count of `0`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast of a count
+    /// from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::SummaryStatisticsExt;
+    /// use std::f64;
+    ///
+    /// let a = array![[1., f64::NAN, 3.], [f64::INFINITY, f64::NAN, 6.]];
+    /// let (means, counts) = a.mean_axis_skipnan(Axis(0));
+    /// assert_eq!(counts, array![1, 0, 2]);
+    /// assert_eq!(means[0], 1.);
+    /// assert!(means[1].is_nan());
+    /// assert_eq!(means[2], 4.5);
+    /// ```
+    fn mean_axis_skipnan(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<usize, D::Smaller>)
+        where
+            A: Float + FromPrimitive,
+            D: RemoveAxis;
+
+    /// Returns the [standard error of the mean] of all elements in the array:
+    ///
+    /// ```text
+    ///        σ
+    /// SEM = ――
+    ///       √n
+    /// ```
+    ///
+    /// where `σ` is the standard deviation of the elements computed with
+    /// `ddof` "delta degrees of freedom" (see `ndarray`'s [`ArrayBase::std_axis`])
+    /// and `n` is the number of elements.
+    ///
+    /// Returns NaN if the array is empty.
+    ///
+    /// **Panics** if the array is not empty and `ddof` is less than zero or
+    /// greater than or equal to `n`, or if the type cast of `n` from `usize`
+    /// to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![2., 4., 4., 4., 5., 5., 7., 9.];
+    /// // the population standard deviation is 2
+    /// assert_eq!(a.sem(0.), 2. / 8f64.sqrt());
+    /// ```
+    ///
+    /// [standard error of the mean]: https://en.wikipedia.org/wiki/Standard_error
+    /// [`ArrayBase::std_axis`]: https://docs.rs/ndarray/0.12/ndarray/struct.ArrayBase.html#method.std_axis
+    fn sem(&self, ddof: A) -> A
+        where
+            A: Float + FromPrimitive;
+
+    /// Returns the number of elements in the array that are not equal to zero
+    /// (as NumPy's `count_nonzero`).
+    ///
+    /// NaN values are counted, since they are not equal to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::SummaryStatisticsExt;
+    ///
+    /// let a = array![[0, 3, 0], [-1, 0, 0]];
+    /// assert_eq!(a.count_nonzero(), 2);
+    /// assert_eq!(a.fraction_nonzero(), 1. / 3.);
+    /// ```
+    fn count_nonzero(&self) -> usize
+        where
+            A: Zero;
+
+    /// Returns the fraction of elements in the array that are not equal to zero,
+    /// i.e. [`count_nonzero`](#tymethod.count_nonzero) divided by the number
+    /// of elements.
+    ///
+    /// Returns NaN if the array is empty.
+    fn fraction_nonzero(&self) -> f64
+        where
+            A: Zero;
+
+    /// Returns the number of elements not equal to zero in each 1-dimensional
+    /// lane along `axis`.
+    ///
+    /// See [`count_nonzero`](#tymethod.count_nonzero) for details.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    fn count_nonzero_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+        where
+            A: Zero,
+            D: RemoveAxis;
 }
 
 mod means;
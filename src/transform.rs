@@ -0,0 +1,742 @@
+use histogram::class_counts;
+use histogram::errors::BinNotFound;
+use ndarray::prelude::*;
+use ndarray::{s, Data, DataMut, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for `ArrayBase` providing methods
+/// to transform the elements of an array (e.g. clipping).
+pub trait TransformExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a new array where every element of `self` has been clipped
+    /// to the interval `[min, max]`: elements smaller than `min` are replaced
+    /// by `min`, elements greater than `max` are replaced by `max`.
+    ///
+    /// Elements that cannot be compared with `min` and `max` (e.g. NaN values)
+    /// are left untouched.
+    ///
+    /// **Panics** if `min` is greater than `max` or if they cannot be compared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::TransformExt;
+    ///
+    /// let a = array![-2, 0, 5, 10];
+    /// assert_eq!(a.clip(0, 8), array![0, 0, 5, 8]);
+    /// ```
+    fn clip(&self, min: A, max: A) -> Array<A, D>
+    where
+        A: PartialOrd + Clone;
+
+    /// Clip **in place** every element of `self` to the interval `[min, max]`.
+    ///
+    /// See [`clip`](#tymethod.clip) for details.
+    ///
+    /// **Panics** if `min` is greater than `max` or if they cannot be compared.
+    fn clip_mut(&mut self, min: A, max: A)
+    where
+        A: PartialOrd + Clone,
+        S: DataMut;
+
+    /// Return a new 1-dimensional array containing only the finite elements
+    /// of `self` (i.e. dropping NaN and infinite values), in logical order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::TransformExt;
+    /// use std::f64;
+    ///
+    /// let a = array![[1., f64::NAN], [f64::INFINITY, 2.]];
+    /// assert_eq!(a.retain_finite(), array![1., 2.]);
+    /// ```
+    fn retain_finite(&self) -> Array1<A>
+    where
+        A: Float;
+
+    /// Write the finite elements of `self` into `out`, in logical order.
+    ///
+    /// `out` is cleared first: its allocation is reused, so that
+    /// no new buffer is needed when calling this method repeatedly.
+    ///
+    /// See [`retain_finite`](#tymethod.retain_finite) for details.
+    fn retain_finite_into(&self, out: &mut Vec<A>)
+    where
+        A: Float;
+
+    /// Return a new array where NaN, `+∞` and `-∞` values have been replaced
+    /// by `nan`, `posinf` and `neginf` respectively (as NumPy's `nan_to_num`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::TransformExt;
+    /// use std::f64;
+    ///
+    /// let a = array![f64::NAN, 1., f64::INFINITY];
+    /// assert_eq!(a.nan_to_num(0., 1e6, -1e6), array![0., 1., 1e6]);
+    /// ```
+    fn nan_to_num(&self, nan: A, posinf: A, neginf: A) -> Array<A, D>
+    where
+        A: Float;
+
+    /// Replace **in place** NaN, `+∞` and `-∞` values with
+    /// `nan`, `posinf` and `neginf` respectively.
+    ///
+    /// See [`nan_to_num`](#tymethod.nan_to_num) for details.
+    fn nan_to_num_mut(&mut self, nan: A, posinf: A, neginf: A)
+    where
+        A: Float,
+        S: DataMut;
+
+    /// Return a new array where the intensity levels of `self`, in
+    /// `0..n_levels`, have been remapped using [histogram equalization]:
+    /// the output levels are spread over the whole `0..n_levels` range,
+    /// as uniformly as possible.
+    ///
+    /// Let `cdf(v)` be the number of elements smaller than or equal to `v`,
+    /// `cdf_min` the smallest non-zero value of `cdf` and `N` the number of
+    /// elements. Each level `v` is mapped to
+    ///
+    /// ```text
+    ///        ⎛ cdf(v) - cdf_min                 ⎞
+    /// round ⎜ ――――――――――――――― ⋅ (n_levels - 1) ⎟
+    ///        ⎝    N - cdf_min                   ⎠
+    /// ```
+    ///
+    /// If all elements have the same level (or the array is empty)
+    /// the array is returned unchanged.
+    ///
+    /// It returns `Err(BinNotFound)` if any level is greater than or equal to `n_levels`.
+    ///
+    /// **Panics** if the type cast of a level from `u64` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::TransformExt;
+    ///
+    /// let image: ndarray::Array2<u8> = array![[100, 101], [101, 102]];
+    /// assert_eq!(
+    ///     image.histogram_equalize(256).unwrap(),
+    ///     array![[0, 170], [170, 255]]
+    /// );
+    /// ```
+    ///
+    /// [histogram equalization]: https://en.wikipedia.org/wiki/Histogram_equalization
+    fn histogram_equalize(&self, n_levels: usize) -> Result<Array<A, D>, BinNotFound>
+    where
+        A: Clone + Into<usize> + FromPrimitive;
+
+    /// Return a new array where each 1-dimensional lane along `axis` has been
+    /// divided by its `norm`, so that the norm of every lane is equal to 1
+    /// (as scikit-learn's `normalize`).
+    ///
+    /// Lanes with a norm equal to zero (i.e. lanes whose elements are all zero)
+    /// are left unchanged. NaN values propagate to the whole lane.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{Norm, TransformExt};
+    ///
+    /// let a = array![[3., -4.], [0., 0.], [1., 1.]];
+    /// assert_eq!(
+    ///     a.normalize_axis(Axis(1), Norm::L2),
+    ///     array![[0.6, -0.8], [0., 0.], [1. / 2f64.sqrt(), 1. / 2f64.sqrt()]]
+    /// );
+    /// assert_eq!(
+    ///     a.normalize_axis(Axis(1), Norm::L1),
+    ///     array![[3. / 7., -4. / 7.], [0., 0.], [0.5, 0.5]]
+    /// );
+    /// ```
+    fn normalize_axis(&self, axis: Axis, norm: Norm) -> Array<A, D>
+    where
+        A: Float;
+}
+
+impl<A, S, D> TransformExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn clip(&self, min: A, max: A) -> Array<A, D>
+    where
+        A: PartialOrd + Clone,
+    {
+        let mut clipped = self.to_owned();
+        clipped.clip_mut(min, max);
+        clipped
+    }
+
+    fn clip_mut(&mut self, min: A, max: A)
+    where
+        A: PartialOrd + Clone,
+        S: DataMut,
+    {
+        assert!(min <= max, "`min` has to be smaller than or equal to `max`.");
+        self.map_inplace(|x| {
+            if *x < min {
+                *x = min.clone()
+            } else if *x > max {
+                *x = max.clone()
+            }
+        });
+    }
+
+    fn retain_finite(&self) -> Array1<A>
+    where
+        A: Float,
+    {
+        let mut finite = Vec::new();
+        self.retain_finite_into(&mut finite);
+        Array1::from_vec(finite)
+    }
+
+    fn retain_finite_into(&self, out: &mut Vec<A>)
+    where
+        A: Float,
+    {
+        out.clear();
+        out.extend(self.iter().filter(|x| x.is_finite()));
+    }
+
+    fn nan_to_num(&self, nan: A, posinf: A, neginf: A) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut replaced = self.to_owned();
+        replaced.nan_to_num_mut(nan, posinf, neginf);
+        replaced
+    }
+
+    fn nan_to_num_mut(&mut self, nan: A, posinf: A, neginf: A)
+    where
+        A: Float,
+        S: DataMut,
+    {
+        self.mapv_inplace(|x| {
+            if x.is_nan() {
+                nan
+            } else if x == A::infinity() {
+                posinf
+            } else if x == A::neg_infinity() {
+                neginf
+            } else {
+                x
+            }
+        });
+    }
+
+    fn histogram_equalize(&self, n_levels: usize) -> Result<Array<A, D>, BinNotFound>
+    where
+        A: Clone + Into<usize> + FromPrimitive,
+    {
+        let mut cdf = class_counts(self.iter(), n_levels)?;
+        for level in 1..n_levels {
+            cdf[level] += cdf[level - 1];
+        }
+        let n = self.len();
+        let cdf_min = cdf.iter().cloned().find(|&c| c > 0).unwrap_or(0);
+        if n == cdf_min {
+            return Ok(self.to_owned());
+        }
+        // The products below are computed in `u64`: they would overflow
+        // `usize` on 32-bit targets for realistic image sizes.
+        let range = (n - cdf_min) as u64;
+        let max_level = (n_levels - 1) as u64;
+        Ok(self.mapv(|level| {
+            // Integer rounding to the nearest level, ties rounded up
+            let scaled = 2 * (cdf[level.into()] - cdf_min) as u64 * max_level + range;
+            A::from_u64(scaled / (2 * range)).unwrap()
+        }))
+    }
+
+    fn normalize_axis(&self, axis: Axis, norm: Norm) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let mut normalized = self.to_owned();
+        Zip::from(normalized.lanes_mut(axis)).apply(|mut lane| {
+            let max_abs = || lane.fold(A::zero(), |acc, &x| {
+                if x.is_nan() || acc.is_nan() { A::nan() } else { acc.max(x.abs()) }
+            });
+            // The lane is divided by `scale` first, then by `value`.
+            let (scale, value) = match norm {
+                Norm::L1 => (A::one(), lane.fold(A::zero(), |acc, &x| acc + x.abs())),
+                Norm::L2 => {
+                    // The squares are summed after scaling the lane by its maximum
+                    // absolute value, so that they neither overflow nor underflow.
+                    let max_abs = max_abs();
+                    if max_abs == A::zero() {
+                        (A::one(), A::zero())
+                    } else {
+                        let sum = lane.fold(A::zero(), |acc, &x| acc + (x / max_abs) * (x / max_abs));
+                        (max_abs, sum.sqrt())
+                    }
+                }
+                Norm::Max => (A::one(), max_abs()),
+            };
+            if value != A::zero() {
+                lane.mapv_inplace(|x| x / scale / value);
+            }
+        });
+        normalized
+    }
+}
+
+/// Norm used by [`normalize_axis`] to scale each lane.
+///
+/// [`normalize_axis`]: trait.TransformExt.html#tymethod.normalize_axis
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Norm {
+    /// Sum of absolute values: `∑ᵢ |xᵢ|`.
+    L1,
+    /// Euclidean norm: `sqrt(∑ᵢ xᵢ²)`.
+    L2,
+    /// Maximum absolute value: `maxᵢ |xᵢ|`.
+    Max,
+}
+
+/// Extension trait for `ArrayBase` providing methods
+/// to transform 1-dimensional arrays (e.g. smoothing).
+pub trait Transform1dExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the exponentially weighted moving average of the array,
+    /// with smoothing factor `alpha`:
+    ///
+    /// ```text
+    /// y₀ = x₀
+    /// yₜ = α xₜ + (1 - α) yₜ₋₁
+    /// ```
+    ///
+    /// The larger `alpha`, the less smoothing is applied:
+    /// with `alpha` equal to `1` the array is returned unchanged.
+    ///
+    /// **Panics** if `alpha` is not in the interval `(0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Transform1dExt;
+    ///
+    /// let a = array![1., 3., 3.];
+    /// assert_eq!(a.ewma(0.5), array![1., 2., 2.5]);
+    /// ```
+    fn ewma(&self, alpha: A) -> Array1<A>
+    where
+        A: Float;
+}
+
+impl<A, S> Transform1dExt<A, S> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    fn ewma(&self, alpha: A) -> Array1<A>
+    where
+        A: Float,
+    {
+        assert!(alpha > A::zero() && alpha <= A::one(),
+                "`alpha` has to be in the interval (0, 1].");
+        let mut average = self.to_owned();
+        let mut previous: Option<A> = None;
+        for y in average.iter_mut() {
+            if let Some(previous) = previous {
+                *y = alpha * *y + (A::one() - alpha) * previous;
+            }
+            previous = Some(*y);
+        }
+        average
+    }
+}
+
+/// Extension trait for `ArrayBase` providing methods
+/// to transform 2-dimensional arrays (e.g. downsampling a series of points).
+pub trait Transform2dExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Downsample a series of points to `threshold` points using the
+    /// [Largest-Triangle-Three-Buckets] algorithm, which preserves
+    /// the visual shape of the series (e.g. peaks and troughs) when plotted.
+    ///
+    /// `self` has shape `(n, 2)`: each row is a point `(x, y)` and the points
+    /// are expected to be sorted by `x`. The first and the last point are
+    /// always kept; the others are split in `threshold - 2` buckets and,
+    /// in each bucket, the point forming the largest triangle with the point
+    /// previously selected and the average of the next bucket is kept.
+    ///
+    /// If `threshold` is greater than or equal to `n`, a copy of `self` is returned.
+    ///
+    /// **Panics** if `threshold` is smaller than `2` or if `self` does not have
+    /// two columns.
+    ///
+    /// [Largest-Triangle-Three-Buckets]: https://skemman.is/handle/1946/15343
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// extern crate ndarray;
+    /// extern crate ndarray_stats;
+    /// use ndarray::array;
+    /// use ndarray_stats::Transform2dExt;
+    ///
+    /// let points = array![[0., 0.], [1., 0.], [2., 5.], [3., 0.], [4., 0.]];
+    /// assert_eq!(points.lttb_downsample(3), array![[0., 0.], [2., 5.], [4., 0.]]);
+    /// ```
+    fn lttb_downsample(&self, threshold: usize) -> Array2<A>
+    where
+        A: Float + FromPrimitive;
+}
+
+impl<A, S> Transform2dExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn lttb_downsample(&self, threshold: usize) -> Array2<A>
+    where
+        A: Float + FromPrimitive,
+    {
+        assert!(threshold >= 2, "`threshold` has to be greater than or equal to 2.");
+        assert_eq!(self.cols(), 2, "Each point must have two coordinates, (x, y).");
+        let n = self.rows();
+        if threshold >= n {
+            return self.to_owned();
+        }
+        let (x, y) = (self.column(0), self.column(1));
+        let mut selected = Vec::with_capacity(threshold);
+        selected.push(0);
+        // Bucket `i` holds the points with index in `bucket_start(i)..bucket_start(i + 1)`
+        let bucket_size = (n - 2) as f64 / (threshold - 2) as f64;
+        let bucket_start = |i: usize| (i as f64 * bucket_size).floor() as usize + 1;
+        let mut previous = 0;
+        for i in 0..threshold - 2 {
+            let (next_start, next_end) = (bucket_start(i + 1), bucket_start(i + 2).min(n));
+            let next_len = A::from_usize(next_end - next_start).unwrap();
+            let x_next = x.slice(s![next_start..next_end]).sum() / next_len;
+            let y_next = y.slice(s![next_start..next_end]).sum() / next_len;
+
+            let (x_previous, y_previous) = (x[previous], y[previous]);
+            let mut max_area = A::neg_infinity();
+            for j in bucket_start(i)..bucket_start(i + 1) {
+                // Twice the area of the triangle: the factor is irrelevant for the comparison
+                let area = ((x_previous - x_next) * (y[j] - y_previous)
+                    - (x_previous - x[j]) * (y_next - y_previous))
+                    .abs();
+                if area > max_area {
+                    max_area = area;
+                    previous = j;
+                }
+            }
+            selected.push(previous);
+        }
+        selected.push(n - 1);
+        self.select(Axis(0), &selected)
+    }
+}
+
+#[cfg(test)]
+mod clip_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_clip() {
+        let a = array![-2, 0, 5, 10];
+        assert_eq!(a.clip(0, 8), array![0, 0, 5, 8]);
+    }
+
+    #[test]
+    fn test_clip_mut() {
+        let mut a = array![[-1.5, 0.3], [2.7, 1.]];
+        a.clip_mut(0., 1.);
+        assert_eq!(a, array![[0., 0.3], [1., 1.]]);
+    }
+
+    #[test]
+    fn test_clip_keeps_nan() {
+        let a = array![f64::NAN, 3.];
+        let clipped = a.clip(0., 1.);
+        assert!(clipped[0].is_nan());
+        assert_eq!(clipped[1], 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clip_with_min_greater_than_max() {
+        array![1, 2, 3].clip(3, 2);
+    }
+}
+
+#[cfg(test)]
+mod retain_finite_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_retain_finite_preserves_order() {
+        let a = array![f64::NAN, 3., f64::INFINITY, -1., f64::NAN, 2., f64::NEG_INFINITY];
+        assert_eq!(a.retain_finite(), array![3., -1., 2.]);
+    }
+
+    #[test]
+    fn test_retain_finite_without_finite_values() {
+        let a = array![f64::NAN, f64::INFINITY];
+        assert_eq!(a.retain_finite(), Array1::<f64>::zeros(0));
+    }
+
+    #[test]
+    fn test_retain_finite_into_reuses_buffer() {
+        let mut out = Vec::with_capacity(10);
+        out.push(42.);
+        let a = array![[1., f64::NAN], [2., 3.]];
+        a.retain_finite_into(&mut out);
+        assert_eq!(out, vec![1., 2., 3.]);
+        assert!(out.capacity() >= 10);
+    }
+}
+
+#[cfg(test)]
+mod nan_to_num_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_nan_to_num_replaces_every_non_finite_kind() {
+        let a = array![[f64::NAN, -2.5], [f64::INFINITY, f64::NEG_INFINITY]];
+        assert_eq!(a.nan_to_num(0., 10., -10.), array![[0., -2.5], [10., -10.]]);
+    }
+
+    #[test]
+    fn test_nan_to_num_mut() {
+        let mut a = array![1., f64::NEG_INFINITY, f64::NAN, 0., f64::INFINITY];
+        a.nan_to_num_mut(-1., f64::MAX, f64::MIN);
+        assert_eq!(a, array![1., f64::MIN, -1., 0., f64::MAX]);
+    }
+}
+
+#[cfg(test)]
+mod histogram_equalize_tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_histogram_equalize_stretches_low_contrast_data() {
+        let image: Array2<u8> = array![
+            [120, 121, 121, 122],
+            [121, 122, 122, 123],
+            [122, 122, 123, 124],
+        ];
+        let equalized = image.histogram_equalize(256).unwrap();
+        assert_eq!(equalized.dim(), image.dim());
+        assert_eq!(*equalized.iter().min().unwrap(), 0);
+        assert_eq!(*equalized.iter().max().unwrap(), 255);
+        // cdf = [1, 4, 9, 11, 12] for levels 120..=124, cdf_min = 1
+        assert_eq!(
+            equalized,
+            array![
+                [0, 70, 70, 185],
+                [70, 185, 185, 232],
+                [185, 185, 232, 255],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_histogram_equalize_preserves_order() {
+        let a: Array1<usize> = array![3, 0, 7, 7, 2, 5, 3];
+        let equalized = a.histogram_equalize(8).unwrap();
+        for i in 0..a.len() {
+            for j in 0..a.len() {
+                if a[i] <= a[j] {
+                    assert!(equalized[i] <= equalized[j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_histogram_equalize_of_constant_array() {
+        let a: Array1<u8> = array![4, 4, 4];
+        assert_eq!(a.histogram_equalize(8).unwrap(), a);
+        let empty: Array1<u8> = array![];
+        assert_eq!(empty.histogram_equalize(8).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_histogram_equalize_with_level_out_of_range() {
+        let a: Array1<u8> = array![1, 8, 2];
+        assert!(a.histogram_equalize(8).is_err());
+    }
+}
+
+#[cfg(test)]
+mod normalize_axis_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_normalize_rows_to_unit_l2_norm() {
+        let a = array![[1., 2., 2.], [-0.5, 3.25, 7.], [1e-3, 0., -2e-3]];
+        let normalized = a.normalize_axis(Axis(1), Norm::L2);
+        for row in normalized.genrows() {
+            let norm = row.fold(0., |acc, &x| acc + x * x).sqrt();
+            assert!((norm - 1.).abs() < 1e-12);
+        }
+        assert_eq!(normalized.row(0), array![1. / 3., 2. / 3., 2. / 3.]);
+    }
+
+    #[test]
+    fn test_normalize_l2_with_very_large_and_very_small_elements() {
+        let a = array![[1e200, 1e200], [1e-200, 0.], [f64::MAX, -f64::MAX]];
+        let normalized = a.normalize_axis(Axis(1), Norm::L2);
+        let sqrt_half = 1. / 2f64.sqrt();
+        assert!((normalized[[0, 0]] - sqrt_half).abs() < 1e-12);
+        assert!((normalized[[0, 1]] - sqrt_half).abs() < 1e-12);
+        assert_eq!(normalized.row(1), array![1., 0.]);
+        assert!((normalized[[2, 0]] - sqrt_half).abs() < 1e-12);
+        assert!((normalized[[2, 1]] + sqrt_half).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_columns() {
+        let a = array![[2., -1.], [-6., 3.]];
+        assert_eq!(a.normalize_axis(Axis(0), Norm::Max), array![[1. / 3., -1. / 3.], [-1., 1.]]);
+        assert_eq!(a.normalize_axis(Axis(0), Norm::L1), array![[0.25, -0.25], [-0.75, 0.75]]);
+    }
+
+    #[test]
+    fn test_normalize_leaves_zero_lanes_unchanged() {
+        let a = array![[0., 0.], [0., 5.]];
+        for &norm in &[Norm::L1, Norm::L2, Norm::Max] {
+            assert_eq!(a.normalize_axis(Axis(1), norm), array![[0., 0.], [0., 1.]]);
+        }
+    }
+
+    #[test]
+    fn test_normalize_propagates_nan() {
+        let a = array![[f64::NAN, 1.], [2., 0.]];
+        for &norm in &[Norm::L1, Norm::L2, Norm::Max] {
+            let normalized = a.normalize_axis(Axis(1), norm);
+            assert!(normalized.row(0).iter().all(|x| x.is_nan()));
+            assert_eq!(normalized.row(1), array![1., 0.]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ewma_tests {
+    use super::*;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_ewma_with_alpha_one_is_identity() {
+        let a = array![3., -1., 4., 1., 5.];
+        assert_eq!(a.ewma(1.), a);
+    }
+
+    #[test]
+    fn test_ewma_recursion() {
+        let a = array![10., 20., 30., 20.];
+        // y = [10, 0.2*20 + 0.8*10, 0.2*30 + 0.8*12, 0.2*20 + 0.8*15.6]
+        let expected = array![10., 12., 15.6, 16.48];
+        assert!(a.ewma(0.2).all_close(&expected, 1e-12));
+    }
+
+    #[test]
+    fn test_ewma_of_empty_array() {
+        let a: Array1<f64> = array![];
+        assert_eq!(a.ewma(0.5), a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ewma_with_zero_alpha() {
+        array![1., 2.].ewma(0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ewma_with_nan_alpha() {
+        array![1., 2.].ewma(f64::NAN);
+    }
+}
+
+#[cfg(test)]
+mod lttb_tests {
+    use super::*;
+    use ndarray::array;
+
+    fn sine_wave(n: usize) -> Array2<f64> {
+        Array2::from_shape_fn((n, 2), |(i, j)| {
+            let x = i as f64 / 10.;
+            if j == 0 { x } else { x.sin() }
+        })
+    }
+
+    #[test]
+    fn test_lttb_keeps_endpoints_and_length() {
+        let points = sine_wave(1000);
+        for &threshold in &[2, 3, 10, 57, 999] {
+            let sampled = points.lttb_downsample(threshold);
+            assert_eq!(sampled.dim(), (threshold, 2));
+            assert_eq!(sampled.row(0), points.row(0));
+            assert_eq!(sampled.row(threshold - 1), points.row(999));
+            // points are picked once and in order
+            assert!((1..threshold).all(|i| sampled[[i - 1, 0]] < sampled[[i, 0]]));
+        }
+    }
+
+    #[test]
+    fn test_lttb_keeps_spikes() {
+        let mut points = Array2::from_shape_fn((101, 2), |(i, j)| if j == 0 { i as f64 } else { 0. });
+        points[[37, 1]] = 10.;
+        points[[80, 1]] = -10.;
+        let sampled = points.lttb_downsample(4);
+        assert_eq!(sampled.column(0), array![0., 37., 80., 100.]);
+    }
+
+    #[test]
+    fn test_lttb_with_threshold_larger_than_the_number_of_points() {
+        let points = sine_wave(5);
+        assert_eq!(points.lttb_downsample(5), points);
+        assert_eq!(points.lttb_downsample(8), points);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lttb_with_threshold_smaller_than_two() {
+        sine_wave(5).lttb_downsample(1);
+    }
+}
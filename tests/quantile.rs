@@ -1,12 +1,19 @@
-#[macro_use(array)]
+#[macro_use(array, s)]
 extern crate ndarray;
 extern crate ndarray_stats;
+extern crate noisy_float;
+#[macro_use]
+extern crate quickcheck;
 
 use ndarray::prelude::*;
+use noisy_float::types::{n64, N64};
 use ndarray_stats::{
-    interpolate::{Higher, Linear, Lower, Midpoint, Nearest},
+    interpolate::{Higher, Interpolate, Linear, Lower, Midpoint, Nearest},
+    errors::{EmptyInput, MultiInputError},
+    median_of_two_sorted,
     QuantileExt,
     Quantile1dExt,
+    Quantile2dExt,
 };
 
 #[test]
@@ -63,6 +70,206 @@ fn test_max_skipnan_all_nan() {
     assert!(a.max_skipnan().is_nan());
 }
 
+#[test]
+fn test_peak_to_peak_skipnan() {
+    let a = array![[3., ::std::f64::NAN, -2.], [::std::f64::NAN, 7.5, 0.]];
+    assert_eq!(a.peak_to_peak_skipnan(), Some(9.5));
+
+    let a = array![1., ::std::f64::INFINITY, -4., ::std::f64::NEG_INFINITY];
+    assert_eq!(a.peak_to_peak_skipnan(), Some(5.));
+
+    let a = array![::std::f64::NAN, 2.];
+    assert_eq!(a.peak_to_peak_skipnan(), Some(0.));
+}
+
+#[test]
+fn test_peak_to_peak_skipnan_without_finite_elements() {
+    let a = arr2(&[[::std::f64::NAN; 3]; 2]);
+    assert_eq!(a.peak_to_peak_skipnan(), None);
+    let a: Array1<f64> = array![];
+    assert_eq!(a.peak_to_peak_skipnan(), None);
+}
+
+#[test]
+fn test_quantile_ordinal_mut_with_integers() {
+    let mut a = array![15, 3, 9, 12, 6];
+    assert_eq!(a.quantile_ordinal_mut::<Nearest>(0.7), Some(12));
+    assert_eq!(a.quantile_ordinal_mut::<Lower>(0.7), Some(9));
+    assert_eq!(a.quantile_ordinal_mut::<Higher>(0.7), Some(12));
+
+    let mut b: Array1<i32> = array![];
+    assert_eq!(b.quantile_ordinal_mut::<Nearest>(0.5), None);
+}
+
+#[test]
+fn test_quantile_ordinal_mut_matches_quantile_mut() {
+    let mut a = array![n64(0.5), n64(-1.), n64(3.), n64(2.)];
+    for &q in &[0., 0.2, 0.5, 0.9, 1.] {
+        assert_eq!(
+            a.quantile_ordinal_mut::<Nearest>(q),
+            a.quantile_mut::<Nearest>(q)
+        );
+    }
+    // Arithmetic strategies go through `quantile_mut`
+    assert_eq!(a.quantile_mut::<Midpoint>(0.5), Some(n64(1.25)));
+}
+
+#[test]
+fn test_quartiles_of_one_to_one_hundred() {
+    let mut a: Array1<i32> = (1..101).collect();
+    assert_eq!(a.first_quartile_mut::<Lower>(), Some(25));
+    assert_eq!(a.third_quartile_mut::<Higher>(), Some(76));
+    assert_eq!(a.quartiles_mut::<Nearest>(), Some((26, 51, 75)));
+
+    let mut a: Array1<N64> = (1..101).map(|x| n64(x as f64)).collect();
+    assert_eq!(a.first_quartile_mut::<Linear>(), Some(n64(25.75)));
+    assert_eq!(a.third_quartile_mut::<Linear>(), Some(n64(75.25)));
+    assert_eq!(
+        a.quartiles_mut::<Linear>(),
+        Some((n64(25.75), n64(50.5), n64(75.25)))
+    );
+}
+
+#[test]
+fn test_quantiles_mut_matches_quantile_mut() {
+    let data = array![
+        355, 453, 452, 391, 289, 343, 44, 154, 271, 44, 314, 276, 160, 469, 191, 138, 163, 308,
+        395, 3, 416, 391, 210, 354, 200,
+    ];
+    let qs = [0.9, 0., 0.33, 0.5, 1., 0.33, 0.01, 0.75];
+    let quantiles = data.clone().quantiles_mut::<Midpoint>(&qs).unwrap();
+    for (&q, quantile) in qs.iter().zip(quantiles.iter()) {
+        assert_eq!(data.clone().quantile_mut::<Midpoint>(q).unwrap(), *quantile);
+    }
+}
+
+#[test]
+fn test_quantiles_mut_with_empty_array() {
+    let mut a: Array1<i32> = array![];
+    assert_eq!(a.quantiles_mut::<Nearest>(&[0.5]), None);
+    assert_eq!(a.quartiles_mut::<Nearest>(), None);
+}
+
+#[test]
+fn test_winsorize_mut() {
+    let mut a = array![7, 1000, 2, 15, 11, 3, -1000, 19, 9, 4, 16, 5, 12, 6, 13, 8, 17, 14, 10, 18];
+    // Sorted: -1000, 2, 3, ..., 19, 1000. With `Lower`, the 0.1-quantile is
+    // the element at index floor(0.1 * 19) = 1 (i.e. 2), the 0.9-quantile is
+    // the element at index floor(0.9 * 19) = 17 (i.e. 18).
+    a.winsorize_mut::<Lower>(0.1, 0.9);
+    // The order of the elements is not preserved.
+    let mut values = a.to_vec();
+    values.sort();
+    let mut expected: Vec<i32> = (2..19).collect();
+    expected.insert(0, 2);
+    expected.extend(&[18, 18]);
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn test_winsorize_mut_with_empty_array() {
+    let mut a = Array1::<i32>::zeros(0);
+    a.winsorize_mut::<Nearest>(0.1, 0.9);
+    assert!(a.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_winsorize_mut_with_invalid_quantiles() {
+    let mut a = array![1, 2, 3];
+    a.winsorize_mut::<Nearest>(0.5, 0.5);
+}
+
+#[test]
+fn test_trim_to_quantile_range_mut() {
+    let data: Vec<i32> = (0..50).map(|i| (i * 37) % 50).collect();
+    let mut a = Array1::from_vec(data);
+    // ceil(49 * 0.11) = 6 and floor(49 * 0.87) = 42
+    let lo = 6;
+    let hi = 43;
+    {
+        let mut inliers = a.trim_to_quantile_range_mut(0.11, 0.87).to_vec();
+        inliers.sort();
+        assert_eq!(inliers, (lo..hi).collect::<Vec<_>>());
+    }
+    assert!(a.slice(s![..lo as usize]).iter().all(|&x| x < lo));
+    assert!(a.slice(s![hi as usize..]).iter().all(|&x| x >= hi));
+}
+
+#[test]
+fn test_trim_to_quantile_range_mut_with_full_range() {
+    let mut a = array![3, 1, 2];
+    let mut inliers = a.trim_to_quantile_range_mut(0., 1.).to_vec();
+    inliers.sort();
+    assert_eq!(inliers, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_trim_to_quantile_range_mut_with_empty_range() {
+    let mut a = array![5, 1];
+    assert_eq!(a.trim_to_quantile_range_mut(0.2, 0.8).len(), 0);
+    let mut b = Array1::<i32>::zeros(0);
+    assert_eq!(b.trim_to_quantile_range_mut(0.2, 0.8).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_trim_to_quantile_range_mut_with_invalid_quantiles() {
+    let mut a = array![1, 2, 3];
+    a.trim_to_quantile_range_mut(0.9, 0.1);
+}
+
+#[test]
+#[should_panic]
+fn test_quantiles_mut_with_invalid_q() {
+    let mut a = array![1, 2, 3];
+    a.quantiles_mut::<Nearest>(&[0.5, 1.5]);
+}
+
+#[test]
+fn test_quantile_rows_mut() {
+    let mut a = array![
+        [n64(1.), n64(5.), n64(3.)],
+        [n64(8.), n64(2.), n64(6.)],
+        [n64(9.), n64(0.), n64(4.)],
+        [n64(7.), n64(7.), n64(7.)],
+    ];
+    let medians = a.quantile_rows_mut::<Linear>(0.5);
+    assert_eq!(medians, array![n64(3.), n64(6.), n64(4.), n64(7.)]);
+    assert_eq!(medians, a.quantile_axis_mut::<Linear>(Axis(1), 0.5));
+}
+
+#[test]
+fn test_argmin_skipnan() {
+    let nan = ::std::f64::NAN;
+    let a = array![nan, 4., nan, -2., nan, -2., 7.];
+    assert_eq!(a.argmin_skipnan(), Some(3));
+
+    let a = array![Some(3), None, Some(1), Some(2), Some(1)];
+    assert_eq!(a.argmin_skipnan(), Some(2));
+}
+
+#[test]
+fn test_argmax_skipnan() {
+    let nan = ::std::f64::NAN;
+    let a = array![1., nan, 9., nan, 2.];
+    assert_eq!(a.argmax_skipnan(), Some(2));
+
+    let a = array![nan, 5., 5.];
+    assert_eq!(a.argmax_skipnan(), Some(1));
+}
+
+#[test]
+fn test_argmin_argmax_skipnan_all_nan() {
+    let a = Array1::from_elem(3, ::std::f64::NAN);
+    assert_eq!(a.argmin_skipnan(), None);
+    assert_eq!(a.argmax_skipnan(), None);
+
+    let a: Array1<f64> = array![];
+    assert_eq!(a.argmin_skipnan(), None);
+    assert_eq!(a.argmax_skipnan(), None);
+}
+
 #[test]
 fn test_quantile_axis_mut_with_odd_axis_length() {
     let mut a = arr2(&[[1, 3, 2, 10], [2, 4, 3, 11], [3, 5, 6, 12]]);
@@ -105,6 +312,49 @@ fn test_quantile_axis_mut_to_get_maximum() {
     assert!(q == arr0(22));
 }
 
+#[test]
+fn test_quantile_axis_into_mut_matches_quantile_axis_mut() {
+    let a = arr2(&[[1, 3, 2, 10], [2, 4, 3, 11], [3, 5, 6, 12], [4, 6, 7, 13], [0, 9, 1, 5]]);
+    let mut out = Array1::zeros(4);
+    for &q in &[0., 0.3, 0.5, 0.75, 1.] {
+        a.clone().quantile_axis_into_mut::<Lower, _>(Axis(0), q, &mut out).unwrap();
+        assert_eq!(out, a.clone().quantile_axis_mut::<Lower>(Axis(0), q));
+        a.clone().quantile_axis_into_mut::<Midpoint, _>(Axis(0), q, &mut out).unwrap();
+        assert_eq!(out, a.clone().quantile_axis_mut::<Midpoint>(Axis(0), q));
+        a.clone().quantile_axis_into_mut::<Linear, _>(Axis(0), q, &mut out).unwrap();
+        assert_eq!(out, a.clone().quantile_axis_mut::<Linear>(Axis(0), q));
+        a.clone().quantile_axis_into_mut::<Higher, _>(Axis(0), q, &mut out).unwrap();
+        assert_eq!(out, a.clone().quantile_axis_mut::<Higher>(Axis(0), q));
+    }
+    let mut out = Array1::zeros(5);
+    a.clone().quantile_axis_into_mut::<Nearest, _>(Axis(1), 0.4, &mut out).unwrap();
+    assert_eq!(out, a.clone().quantile_axis_mut::<Nearest>(Axis(1), 0.4));
+}
+
+#[test]
+fn test_quantile_axis_into_mut_with_wrong_output_shape() {
+    let mut a = arr2(&[[1, 3, 2], [2, 4, 3]]);
+    let mut out = Array1::zeros(2);
+    assert_eq!(
+        a.quantile_axis_into_mut::<Lower, _>(Axis(0), 0.5, &mut out),
+        Err(MultiInputError::ShapeMismatch {
+            first: vec![3],
+            second: vec![2],
+        })
+    );
+    assert_eq!(out, array![0, 0]);
+}
+
+#[test]
+fn test_quantile_axis_into_mut_with_zero_axis_length() {
+    let mut a = Array2::<i32>::zeros((5, 0));
+    let mut out = Array1::zeros(5);
+    assert_eq!(
+        a.quantile_axis_into_mut::<Lower, _>(Axis(1), 0.5, &mut out),
+        Err(MultiInputError::EmptyInput)
+    );
+}
+
 #[test]
 fn test_quantile_axis_skipnan_mut_higher_opt_i32() {
     let mut a = arr2(&[[Some(4), Some(2), None, Some(1), Some(5)], [None; 5]]);
@@ -158,4 +408,221 @@ fn test_midpoint_overflow() {
     let median = a.quantile_mut::<Midpoint>(0.5).unwrap();
     let expected_median = 130;
     assert_eq!(median, expected_median);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_describe_axis_mut() {
+    let mut a: Array2<N64> = array![
+        [4., 1., 9., 3., 7.],
+        [10., -2., 0., 6., 5.],
+        [2., 2., 8., 1., 4.],
+    ].mapv(n64);
+    let description = a.describe_axis_mut(Axis(1));
+    let close = |x: &Array1<N64>, expected: Array1<f64>| {
+        x.iter().zip(&expected).all(|(x, e)| (x.raw() - e).abs() < 1e-12)
+    };
+    assert_eq!(description.count, 5);
+    assert!(close(&description.mean, array![4.8, 3.8, 3.4]));
+    // Sums of squared deviations: 40.8, 92.8 and 31.2
+    assert!(close(description.std.as_ref().unwrap(), array![10.2f64.sqrt(), 23.2f64.sqrt(), 7.8f64.sqrt()]));
+    assert_eq!(description.min, array![1., -2., 1.].mapv(n64));
+    assert_eq!(description.first_quartile, array![3., 0., 2.].mapv(n64));
+    assert_eq!(description.median, array![4., 5., 2.].mapv(n64));
+    assert_eq!(description.third_quartile, array![7., 6., 4.].mapv(n64));
+    assert_eq!(description.max, array![9., 10., 8.].mapv(n64));
+
+    // Quartiles interpolated between two data points
+    let mut b: Array2<N64> = array![[1., 10.], [2., 20.], [4., 40.]].mapv(n64);
+    let description = b.describe_axis_mut(Axis(0));
+    assert_eq!(description.first_quartile, array![1.5, 15.].mapv(n64));
+    assert_eq!(description.third_quartile, array![3., 30.].mapv(n64));
+}
+
+#[test]
+fn test_describe_axis_mut_with_single_observation() {
+    let mut a: Array2<N64> = array![[1.], [2.]].mapv(n64);
+    let description = a.describe_axis_mut(Axis(1));
+    assert_eq!(description.count, 1);
+    assert_eq!(description.mean, array![1., 2.].mapv(n64));
+    assert_eq!(description.std, None);
+    assert_eq!(description.median, array![1., 2.].mapv(n64));
+}
+
+#[test]
+#[should_panic]
+fn test_describe_axis_mut_with_empty_axis() {
+    let mut a = Array2::<N64>::zeros((2, 0));
+    a.describe_axis_mut(Axis(1));
+}
+
+#[test]
+fn test_describe_mut_of_one_to_one_hundred() {
+    // 1..=100, in a scrambled order
+    let mut a: Array1<N64> = (0..100).map(|i| n64(((i * 37) % 100 + 1) as f64)).collect();
+    let summary = a.describe_mut().unwrap();
+    assert_eq!(summary.count, 100);
+    assert_eq!(summary.mean, n64(50.5));
+    // The sample variance of 1..=n is n(n+1)/12
+    assert!((summary.std.unwrap().raw() - (100. * 101. / 12f64).sqrt()).abs() < 1e-12);
+    assert_eq!(summary.min, n64(1.));
+    assert_eq!(summary.first_quartile, n64(25.75));
+    assert_eq!(summary.median, n64(50.5));
+    assert_eq!(summary.third_quartile, n64(75.25));
+    assert_eq!(summary.max, n64(100.));
+}
+
+#[test]
+fn test_describe_mut_with_single_element() {
+    let mut a = array![n64(3.)];
+    let summary = a.describe_mut().unwrap();
+    assert_eq!(summary.count, 1);
+    assert_eq!(summary.mean, n64(3.));
+    assert_eq!(summary.std, None);
+    assert_eq!(summary.min, n64(3.));
+    assert_eq!(summary.median, n64(3.));
+    assert_eq!(summary.max, n64(3.));
+}
+
+#[test]
+fn test_describe_mut_with_empty_array() {
+    let mut a: Array1<N64> = array![];
+    assert_eq!(a.describe_mut(), Err(EmptyInput));
+}
+
+#[test]
+fn test_quantile_mut_extremes_are_min_and_max() {
+    let data: Vec<i64> = (0..101).map(|i| (i * 53) % 101 - 50).collect();
+    let a = Array1::from_vec(data);
+    let (min, max) = (*a.min().unwrap(), *a.max().unwrap());
+    assert_eq!(a.clone().quantile_mut::<Lower>(0.), Some(min));
+    assert_eq!(a.clone().quantile_mut::<Higher>(0.), Some(min));
+    assert_eq!(a.clone().quantile_mut::<Midpoint>(0.), Some(min));
+    assert_eq!(a.clone().quantile_mut::<Linear>(0.), Some(min));
+    assert_eq!(a.clone().quantile_mut::<Nearest>(1.), Some(max));
+    assert_eq!(a.clone().quantile_mut::<Midpoint>(1.), Some(max));
+    assert_eq!(a.clone().quantile_mut::<Linear>(1.), Some(max));
+
+    let b = array![[3, -1, 8], [2, 9, 0]];
+    assert_eq!(b.clone().quantile_axis_mut::<Linear>(Axis(1), 0.), array![-1, 0]);
+    assert_eq!(b.clone().quantile_axis_mut::<Lower>(Axis(0), 1.), array![3, 9, 8]);
+}
+
+/// A user-defined strategy: the sum of the two surrounding data points.
+struct Sum;
+
+impl Interpolate<i64> for Sum {
+    fn needs_lower(_q: f64, _len: usize) -> bool {
+        true
+    }
+    fn needs_higher(_q: f64, _len: usize) -> bool {
+        true
+    }
+    fn interpolate<D: Dimension>(
+        lower: Option<Array<i64, D>>,
+        higher: Option<Array<i64, D>>,
+        _q: f64,
+        _len: usize,
+    ) -> Array<i64, D> {
+        lower.unwrap() + higher.unwrap()
+    }
+}
+
+#[test]
+fn test_quantile_extremes_with_custom_interpolation() {
+    let a = array![[3, -1, 8], [2, 9, 0]];
+    assert_eq!(a.clone().quantile_axis_mut::<Sum>(Axis(1), 0.), array![-2, 0]);
+    assert_eq!(a.clone().quantile_axis_mut::<Sum>(Axis(1), 1.), array![16, 18]);
+    let mut out = Array1::zeros(3);
+    a.clone().quantile_axis_into_mut::<Sum, _>(Axis(0), 1., &mut out).unwrap();
+    assert_eq!(out, array![6, 18, 16]);
+}
+
+#[test]
+fn test_quantile_f64_mut_linear_median_of_two_integers() {
+    let mut a = array![1, 2];
+    assert_eq!(a.quantile_f64_mut::<Linear>(0.5), Some(1.5));
+    // interpolating in `A` truncates the result
+    assert_eq!(a.quantile_mut::<Linear>(0.5), Some(1));
+}
+
+#[test]
+fn test_quantile_f64_mut() {
+    let mut a = array![10u8, 3, 7, 250, 1];
+    assert!((a.quantile_f64_mut::<Linear>(0.3).unwrap() - 3.8).abs() < 1e-12);
+    assert_eq!(a.quantile_f64_mut::<Midpoint>(0.3), Some(5.));
+    assert_eq!(a.quantile_f64_mut::<Lower>(0.3), Some(3.));
+    assert_eq!(a.quantile_f64_mut::<Higher>(0.3), Some(7.));
+    assert_eq!(a.quantile_f64_mut::<Nearest>(0.9), Some(250.));
+}
+
+#[test]
+fn test_quantile_f64_mut_with_empty_array() {
+    let mut a: Array1<i32> = array![];
+    assert_eq!(a.quantile_f64_mut::<Linear>(0.5), None);
+}
+
+fn merged_median<I: Interpolate<i64>>(a: &Array1<i64>, b: &Array1<i64>) -> Option<i64> {
+    let mut merged: Array1<i64> = a.iter().chain(b.iter()).cloned().collect();
+    merged.quantile_mut::<I>(0.5)
+}
+
+quickcheck! {
+    fn median_of_two_sorted_matches_merged_median(xs: Vec<i64>, ys: Vec<i64>) -> bool {
+        let mut xs = xs;
+        let mut ys = ys;
+        xs.sort();
+        ys.sort();
+        let a = Array1::from_vec(xs);
+        let b = Array1::from_vec(ys);
+        let (a_view, b_view) = (a.view(), b.view());
+        median_of_two_sorted::<Lower, _>(&a_view, &b_view) == merged_median::<Lower>(&a, &b)
+            && median_of_two_sorted::<Higher, _>(&a_view, &b_view) == merged_median::<Higher>(&a, &b)
+            && median_of_two_sorted::<Nearest, _>(&a_view, &b_view) == merged_median::<Nearest>(&a, &b)
+            && median_of_two_sorted::<Midpoint, _>(&b_view, &a_view) == merged_median::<Midpoint>(&a, &b)
+    }
+}
+
+#[test]
+fn test_median_of_two_sorted_with_disjoint_ranges() {
+    let a = array![1, 2, 3];
+    let b = array![10, 11, 12, 13];
+    assert_eq!(median_of_two_sorted::<Lower, _>(&a.view(), &b.view()), Some(10));
+    let b = array![10, 11];
+    assert_eq!(median_of_two_sorted::<Midpoint, _>(&a.view(), &b.view()), Some(3));
+    assert_eq!(median_of_two_sorted::<Linear, _>(&b.view(), &a.view()), Some(3));
+}
+
+#[test]
+fn test_median_of_two_sorted_with_empty_arrays() {
+    let a = array![4, 7];
+    let empty: Array1<i64> = array![];
+    assert_eq!(median_of_two_sorted::<Higher, _>(&a.view(), &empty.view()), Some(7));
+    assert_eq!(median_of_two_sorted::<Higher, _>(&empty.view(), &a.view()), Some(7));
+    assert_eq!(median_of_two_sorted::<Higher, _>(&empty.view(), &empty.view()), None);
+}
+
+fn prefix_medians<I: Interpolate<i64>>(a: &Array1<i64>) -> Array1<i64> {
+    (1..=a.len())
+        .map(|n| a.slice(s![..n]).to_owned().quantile_mut::<I>(0.5).unwrap())
+        .collect()
+}
+
+quickcheck! {
+    fn running_median_matches_prefix_medians(xs: Vec<i64>) -> bool {
+        let a = Array1::from_vec(xs);
+        a.running_median::<Lower>() == prefix_medians::<Lower>(&a)
+            && a.running_median::<Higher>() == prefix_medians::<Higher>(&a)
+            && a.running_median::<Nearest>() == prefix_medians::<Nearest>(&a)
+            && a.running_median::<Midpoint>() == prefix_medians::<Midpoint>(&a)
+    }
+}
+
+#[test]
+fn test_running_median_with_floats() {
+    let a = array![3., 1., 4., 1., 5., 9., 2.].mapv(n64);
+    assert_eq!(
+        a.running_median::<Linear>(),
+        array![3., 2., 3., 2., 3., 3.5, 3.].mapv(n64)
+    );
+    assert_eq!(Array1::<N64>::zeros(0).running_median::<Linear>().len(), 0);
+}
@@ -0,0 +1,45 @@
+extern crate ndarray;
+extern crate ndarray_stats;
+
+use ndarray::prelude::*;
+use ndarray_stats::{interpolate::Linear, QuantileExt};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts the allocations performed by the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+}
+
+#[test]
+fn test_quantile_axis_into_mut_does_not_allocate() {
+    let mut a = Array::from_shape_fn((50, 200), |(i, j)| ((i * 37 + j * 101) % 97) as i64);
+    let mut out = Array1::zeros(200);
+    // Warm up the thread-local random number generator used by the selection.
+    a.quantile_axis_into_mut::<Linear, _>(Axis(0), 0.3, &mut out).unwrap();
+    for &q in &[0., 0.3, 0.5, 1.] {
+        let before = allocations();
+        a.quantile_axis_into_mut::<Linear, _>(Axis(0), q, &mut out).unwrap();
+        assert_eq!(allocations(), before);
+    }
+}
@@ -1,8 +1,17 @@
+#[macro_use(s)]
 extern crate ndarray;
 extern crate ndarray_stats;
+#[macro_use]
+extern crate quickcheck;
 
 use ndarray::prelude::*;
-use ndarray_stats::Sort1dExt;
+use quickcheck::TestResult;
+use ndarray_stats::{
+    interpolate::{Higher, Linear, Lower, Midpoint, Nearest},
+    PartitionStats,
+    Quantile1dExt,
+    Sort1dExt,
+};
 
 #[test]
 fn test_partition_mut() {
@@ -34,6 +43,168 @@ fn test_partition_mut() {
     }
 }
 
+quickcheck! {
+    fn argsort_variants_return_sorting_permutations(xs: Vec<u8>) -> bool {
+        // Few distinct values, hence many ties
+        let a = Array1::from_vec(xs.iter().map(|x| x % 4).collect());
+        let is_sorting_permutation = |p: &Array1<usize>| {
+            let mut seen = vec![false; a.len()];
+            for &i in p {
+                seen[i] = true;
+            }
+            p.len() == a.len()
+                && seen.iter().all(|&s| s)
+                && p.map(|&i| a[i]).is_sorted()
+        };
+        let stable = a.argsort();
+        let unstable = a.argsort_unstable();
+        is_sorting_permutation(&stable)
+            && is_sorting_permutation(&unstable)
+            && (1..stable.len()).all(|k| a[stable[k - 1]] < a[stable[k]] || stable[k - 1] < stable[k])
+    }
+
+    fn partition_mut_instrumented_matches_partition_mut(xs: Vec<i64>, pivot_index: usize) -> TestResult {
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let pivot_index = pivot_index % xs.len();
+        let mut a = Array1::from_vec(xs);
+        let mut b = a.clone();
+        let partition_index = a.partition_mut(pivot_index);
+        let (instrumented_index, stats) = b.partition_mut_instrumented(pivot_index);
+        TestResult::from_bool(
+            partition_index == instrumented_index
+                && a == b
+                && 2 * stats.swaps <= a.len()
+        )
+    }
+
+    fn partition_mut_splits_around_pivot(xs: Vec<i64>, pivot_index: usize) -> TestResult {
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let n = xs.len();
+        let pivot_index = pivot_index % n;
+        let pivot_value = xs[pivot_index];
+        let mut a = Array1::from_vec(xs);
+        let partition_index = a.partition_mut(pivot_index);
+        TestResult::from_bool(
+            a[partition_index] == pivot_value
+                && a.slice(s![..partition_index]).iter().all(|&x| x < pivot_value)
+                && a.slice(s![partition_index + 1..]).iter().all(|&x| x >= pivot_value)
+        )
+    }
+
+    fn partition_mut_permutes_elements(xs: Vec<i64>, pivot_index: usize) -> TestResult {
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let pivot_index = pivot_index % xs.len();
+        let mut a = Array1::from_vec(xs.clone());
+        a.partition_mut(pivot_index);
+        let mut expected = xs;
+        expected.sort();
+        let mut rearranged = a.to_vec();
+        rearranged.sort();
+        TestResult::from_bool(rearranged == expected)
+    }
+
+    fn partition_range_mut_leaves_the_rest_untouched(
+        xs: Vec<i64>,
+        lo: usize,
+        hi: usize,
+        pivot_index: usize
+    ) -> TestResult {
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let n = xs.len();
+        let (lo, hi) = (lo % n, hi % n);
+        let (lo, hi) = if lo <= hi { (lo, hi + 1) } else { (hi, lo + 1) };
+        let pivot_index = lo + pivot_index % (hi - lo);
+        let pivot_value = xs[pivot_index];
+        let mut a = Array1::from_vec(xs.clone());
+        let partition_index = a.partition_range_mut(lo, hi, pivot_index);
+        let mut range = a.slice(s![lo..hi]).to_vec();
+        range.sort();
+        let mut expected_range = xs[lo..hi].to_vec();
+        expected_range.sort();
+        TestResult::from_bool(
+            lo <= partition_index && partition_index < hi
+                && a[partition_index] == pivot_value
+                && a.slice(s![lo..partition_index]).iter().all(|&x| x < pivot_value)
+                && a.slice(s![partition_index + 1..hi]).iter().all(|&x| x >= pivot_value)
+                && range == expected_range
+                && a.slice(s![..lo]) == aview1(&xs[..lo])
+                && a.slice(s![hi..]) == aview1(&xs[hi..])
+        )
+    }
+
+    fn sorted_get_mut_returns_order_statistic(xs: Vec<i64>, i: usize) -> TestResult {
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let i = i % xs.len();
+        let mut sorted = xs.clone();
+        sorted.sort();
+        let mut a = Array1::from_vec(xs);
+        TestResult::from_bool(a.sorted_get_mut(i) == sorted[i])
+    }
+
+    fn partition_mut_with_few_distinct_values(xs: Vec<u8>, pivot_index: usize) -> TestResult {
+        // many repeated elements stress the handling of elements equal to the pivot
+        if xs.is_empty() {
+            return TestResult::discard();
+        }
+        let xs: Vec<u8> = xs.into_iter().map(|x| x % 3).collect();
+        let pivot_index = pivot_index % xs.len();
+        let pivot_value = xs[pivot_index];
+        let mut a = Array1::from_vec(xs);
+        let partition_index = a.partition_mut(pivot_index);
+        TestResult::from_bool(
+            a[partition_index] == pivot_value
+                && a.slice(s![..partition_index]).iter().all(|&x| x < pivot_value)
+                && a.slice(s![partition_index + 1..]).iter().all(|&x| x >= pivot_value)
+        )
+    }
+}
+
+#[test]
+fn test_partition_mut_of_single_element() {
+    let mut a = arr1(&[42]);
+    assert_eq!(a.partition_mut(0), 0);
+    assert_eq!(a, arr1(&[42]));
+}
+
+#[test]
+fn test_partition_range_mut() {
+    let mut a = arr1(&[100, 7, 3, 9, 1, 5, 8, -100]);
+    let partition_index = a.partition_range_mut(1, 7, 5);
+    assert_eq!(a[partition_index], 5);
+    assert_eq!(partition_index, 3);
+    for k in 1..partition_index {
+        assert!(a[k] < 5);
+    }
+    for k in partition_index + 1..7 {
+        assert!(a[k] >= 5);
+    }
+    assert_eq!((a[0], a[7]), (100, -100));
+}
+
+#[test]
+#[should_panic]
+fn test_partition_range_mut_with_pivot_outside_of_range() {
+    let mut a = arr1(&[3, 1, 2, 4]);
+    a.partition_range_mut(1, 3, 3);
+}
+
+#[test]
+#[should_panic]
+fn test_sorted_get_mut_out_of_bounds() {
+    let mut a = arr1(&[3]);
+    a.sorted_get_mut(1);
+}
+
 #[test]
 fn test_sorted_get_mut() {
     let a = arr1(&[1, 3, 2, 10]);
@@ -44,3 +215,169 @@ fn test_sorted_get_mut() {
     let j = a.clone().view_mut().sorted_get_mut(3);
     assert_eq!(j, 10);
 }
+
+#[test]
+fn test_sorted_view_quantiles() {
+    let a = arr1(&[
+        355, 453, 452, 391, 289, 343, 44, 154, 271, 44, 314, 276, 160, 469, 191, 138, 163, 308,
+        395, 3, 416, 391, 210, 354, 200,
+    ]);
+    let mut b = a.clone();
+    let sorted = b.sort_into_view_mut();
+    for &q in &[0., 0.1, 0.25, 0.5, 0.67, 0.75, 0.9, 1.] {
+        assert_eq!(sorted.quantile::<Lower>(q), a.clone().quantile_mut::<Lower>(q));
+        assert_eq!(sorted.quantile::<Higher>(q), a.clone().quantile_mut::<Higher>(q));
+        assert_eq!(sorted.quantile::<Nearest>(q), a.clone().quantile_mut::<Nearest>(q));
+        assert_eq!(sorted.quantile::<Midpoint>(q), a.clone().quantile_mut::<Midpoint>(q));
+        assert_eq!(sorted.quantile::<Linear>(q), a.clone().quantile_mut::<Linear>(q));
+    }
+}
+
+#[test]
+fn test_sorted_view_sorts_in_place() {
+    let mut a = arr1(&[3, 1, 2]);
+    {
+        let sorted = a.sort_into_view_mut();
+        assert_eq!(sorted.view(), arr1(&[1, 2, 3]));
+    }
+    assert_eq!(a, arr1(&[1, 2, 3]));
+}
+
+#[test]
+fn test_sorted_view_of_non_contiguous_data() {
+    let mut a = arr2(&[[4, 0], [1, 0], [3, 0], [2, 0]]);
+    {
+        let mut column = a.column_mut(0);
+        let sorted = column.sort_into_view_mut();
+        assert_eq!(sorted.view(), arr1(&[1, 2, 3, 4]));
+    }
+    assert_eq!(a, arr2(&[[1, 0], [2, 0], [3, 0], [4, 0]]));
+}
+
+#[test]
+fn test_sorted_view_percentile_of_score() {
+    // Expected values match the output of SciPy's `percentileofscore`
+    let mut a = arr1(&[3, 1, 4, 3, 2]);
+    let sorted = a.sort_into_view_mut();
+    assert_eq!(sorted.percentile_of_score(&3), 70.);
+    assert_eq!(sorted.percentile_of_score(&0), 0.);
+    assert_eq!(sorted.percentile_of_score(&5), 100.);
+    let mut a = arr1(&[1, 2, 3, 4]);
+    let sorted = a.sort_into_view_mut();
+    assert_eq!(sorted.percentile_of_score(&3), 75.);
+}
+
+#[test]
+fn test_sorted_view_ecdf() {
+    let mut a = arr1(&[3, 1, 4, 3, 2]);
+    let sorted = a.sort_into_view_mut();
+    assert_eq!(sorted.ecdf(&0), 0.);
+    assert_eq!(sorted.ecdf(&1), 0.2);
+    assert_eq!(sorted.ecdf(&3), 0.8);
+    assert_eq!(sorted.ecdf(&10), 1.);
+}
+
+#[test]
+fn test_sorted_view_of_empty_array() {
+    let mut a: Array1<i32> = arr1(&[]);
+    let sorted = a.sort_into_view_mut();
+    assert_eq!(sorted.quantile::<Lower>(0.5), None);
+    assert!(sorted.ecdf(&1).is_nan());
+    assert!(sorted.percentile_of_score(&1).is_nan());
+}
+
+#[test]
+fn test_sorted_range_mut() {
+    let data = arr1(&[
+        355, 453, 452, 391, 289, 343, 44, 154, 271, 44, 314, 276, 160, 469, 191, 138, 163, 308,
+        395, 3, 416, 391, 210, 354, 200,
+    ]);
+    let mut sorted = data.to_vec();
+    sorted.sort();
+    let n = data.len();
+    for lo in 0..=n {
+        for hi in lo..=n {
+            let mut a = data.clone();
+            assert_eq!(a.sorted_range_mut(lo, hi), arr1(&sorted[lo..hi]));
+            // the array has only been rearranged
+            let mut rearranged = a.to_vec();
+            rearranged.sort();
+            assert_eq!(rearranged, sorted);
+        }
+    }
+}
+
+#[test]
+fn test_sorted_range_mut_of_non_contiguous_data() {
+    let mut a = arr1(&[9, 0, 1, 0, 8, 0, 2, 0, 7, 0, 3, 0]);
+    let mut odd = a.slice_mut(s![..;2]);
+    assert_eq!(odd.sorted_range_mut(1, 4), arr1(&[2, 3, 7]));
+}
+
+#[test]
+#[should_panic]
+fn test_sorted_range_mut_out_of_bounds() {
+    let mut a = arr1(&[3, 1, 2]);
+    a.sorted_range_mut(1, 4);
+}
+
+#[test]
+fn test_is_sorted() {
+    assert!(arr1(&[-3, 0, 4, 8]).is_sorted());
+    assert!(!arr1(&[8, 4, 0, -3]).is_sorted());
+    assert!(arr1(&[2, 2, 2]).is_sorted());
+    assert!(!arr1(&[1, 2, 2, 1]).is_sorted());
+    assert!(arr1(&[7]).is_sorted());
+    assert!(Array1::<i32>::zeros(0).is_sorted());
+}
+
+#[test]
+fn test_is_sorted_by() {
+    let reversed = arr1(&[8, 4, 4, 0, -3]);
+    assert!(reversed.is_sorted_by(|a, b| a >= b));
+    assert!(!reversed.is_sorted_by(|a, b| a > b));
+    let floats = arr1(&[0.5, 1., 1.5]);
+    assert!(floats.is_sorted_by(|a, b| a <= b));
+    assert!(!arr1(&[0.5, ::std::f64::NAN, 1.5]).is_sorted_by(|a, b| a <= b));
+}
+
+#[test]
+fn test_sorted_methods_produce_sorted_arrays() {
+    let mut a = arr1(&[5, 3, 9, 1, 1, 0, 7]);
+    assert!(!a.is_sorted());
+    assert!(a.sorted_range_mut(0, 7).is_sorted());
+    assert!(a.is_sorted());
+}
+
+#[test]
+fn test_partition_mut_instrumented_hand_trace() {
+    let mut a = arr1(&[3, 7, 1, 8, 2, 9, 4]);
+    let (partition_index, stats) = a.partition_mut_instrumented(0);
+    // Pivot 3 is moved aside, the left scan stops at 7, the right scan
+    // compares 4, 9 and stops at 2: 7 and 2 are swapped. Then the left scan
+    // compares 1 and stops at 8, the right scan compares 8 and stops at 1,
+    // the scans have crossed and the pivot goes to index 2.
+    assert_eq!(partition_index, 2);
+    assert_eq!(a, arr1(&[1, 2, 3, 8, 7, 9, 4]));
+    assert_eq!(stats, PartitionStats { comparisons: 8, swaps: 1 });
+}
+
+#[test]
+fn test_partition_mut_instrumented_single_element() {
+    let mut a = arr1(&[42]);
+    assert_eq!(a.partition_mut_instrumented(0), (0, PartitionStats::default()));
+}
+
+#[test]
+fn test_argsort_is_stable() {
+    let a = arr1(&[2, 0, 2, 1, 0, 2]);
+    assert_eq!(a.argsort(), arr1(&[1, 4, 3, 0, 2, 5]));
+    assert_eq!(a.argsort_unstable().map(|&i| a[i]), arr1(&[0, 0, 1, 2, 2, 2]));
+}
+
+#[test]
+fn test_argsort_of_empty_array() {
+    let a = Array1::<i32>::zeros(0);
+    assert!(a.argsort().is_empty());
+    assert!(a.argsort_unstable().is_empty());
+}